@@ -0,0 +1,283 @@
+//! Closure based search problem definitions, for searching over types that cannot implement this crate's traits.
+
+use std::{
+    fmt,
+    hash::{Hash, Hasher},
+};
+
+use num::{One, Zero};
+
+use crate::{
+    CostSearchable, FocalScoreable, ReverseCostSearchable, Scoreable, SolutionIdentifiable,
+};
+
+type Transitions<'f, S, C> = Box<dyn Fn(&S) -> Vec<(S, C)> + Send + Sync + 'f>;
+type Estimate<'f, S, C> = Box<dyn Fn(&S) -> C + Send + Sync + 'f>;
+
+/// Search problem defined by closures in place of trait implementations on the state type.
+///
+/// Useful for searching over foreign types, such as tuples or types from other crates, without
+/// wrapping them in a newtype. Create the initial state of a search with [`FnProblem::state`], and
+/// pass it to [`Searcher`](crate::Searcher) with any manager other than the `action_route` managers,
+/// which require transitions described as actions, and the unguided `external` manager, which
+/// requires states that can be ordered and serialized; the states yielded by the search are
+/// [`FnState`]s wrapping your own states. The closures must be `Send` + `Sync`, so that the
+/// `parallel` managers may share the problem between threads. As closures cannot be serialized,
+/// neither can [`FnState`]s, so searches over them cannot be saved with `Searcher::save`.
+///
+/// The heuristic, reverse heuristic and focal heuristic all default to zero, and the predecessors of
+/// every state default to none; supply them with the `with_*` methods where needed by your manager.
+pub struct FnProblem<'f, S, C = usize> {
+    successors: Transitions<'f, S, C>,
+    predecessors: Transitions<'f, S, C>,
+    goal: Box<dyn Fn(&S) -> bool + Send + Sync + 'f>,
+    heuristic: Estimate<'f, S, C>,
+    reverse_heuristic: Estimate<'f, S, C>,
+    focal_heuristic: Estimate<'f, S, C>,
+}
+
+impl<'f, S, C> FnProblem<'f, S, C>
+where
+    S: 'f,
+    C: Zero + 'f,
+{
+    /// Create a new problem from a function yielding the adjacent states of a state, and a function
+    /// identifying solution states. Every transition between states costs one.
+    pub fn new<I>(
+        successors: impl Fn(&S) -> I + Send + Sync + 'f,
+        goal: impl Fn(&S) -> bool + Send + Sync + 'f,
+    ) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        C: One,
+    {
+        Self::new_with_costs(
+            move |state| successors(state).into_iter().map(|s| (s, C::one())),
+            goal,
+        )
+    }
+
+    /// Create a new problem from a function yielding the adjacent states of a state paired with the
+    /// cost of traversing to each, and a function identifying solution states.
+    pub fn new_with_costs<I>(
+        successors: impl Fn(&S) -> I + Send + Sync + 'f,
+        goal: impl Fn(&S) -> bool + Send + Sync + 'f,
+    ) -> Self
+    where
+        I: IntoIterator<Item = (S, C)>,
+    {
+        Self {
+            successors: Box::new(move |state| successors(state).into_iter().collect()),
+            predecessors: Box::new(|_| Vec::new()),
+            goal: Box::new(goal),
+            heuristic: Box::new(|_| C::zero()),
+            reverse_heuristic: Box::new(|_| C::zero()),
+            focal_heuristic: Box::new(|_| C::zero()),
+        }
+    }
+
+    /// Supply a heuristic estimating the cost of reaching a solution from a state, used by the guided
+    /// and A* based managers. Ensure the estimates decrease with the proximity to a solution.
+    pub fn with_heuristic(mut self, heuristic: impl Fn(&S) -> C + Send + Sync + 'f) -> Self {
+        self.heuristic = Box::new(heuristic);
+        self
+    }
+
+    /// Supply a function yielding the states from which a state is directly reachable, paired with
+    /// the cost of traversing from each, used by the bidirectional managers.
+    pub fn with_predecessors<I>(mut self, predecessors: impl Fn(&S) -> I + Send + Sync + 'f) -> Self
+    where
+        I: IntoIterator<Item = (S, C)>,
+    {
+        self.predecessors = Box::new(move |state| predecessors(state).into_iter().collect());
+        self
+    }
+
+    /// Supply a heuristic estimating the cost of reaching a state from the initial state, used by the
+    /// bidirectional A* manager.
+    pub fn with_reverse_heuristic(
+        mut self,
+        reverse_heuristic: impl Fn(&S) -> C + Send + Sync + 'f,
+    ) -> Self {
+        self.reverse_heuristic = Box::new(reverse_heuristic);
+        self
+    }
+
+    /// Supply a secondary heuristic used by the focal search managers.
+    pub fn with_focal_heuristic(
+        mut self,
+        focal_heuristic: impl Fn(&S) -> C + Send + Sync + 'f,
+    ) -> Self {
+        self.focal_heuristic = Box::new(focal_heuristic);
+        self
+    }
+}
+
+impl<'f, S, C> FnProblem<'f, S, C> {
+    /// Wrap a state of this problem, to be used as the initial or goal state of a search.
+    pub fn state(&'f self, state: S) -> FnState<'f, S, C> {
+        FnState {
+            state,
+            problem: self,
+        }
+    }
+}
+
+/// State of a [`FnProblem`], implementing this crate's traits through the problem's closures.
+///
+/// Compared and hashed by the wrapped state alone.
+pub struct FnState<'f, S, C = usize> {
+    /// The wrapped state.
+    pub state: S,
+    problem: &'f FnProblem<'f, S, C>,
+}
+
+impl<S, C> FnState<'_, S, C> {
+    /// Unwrap the state.
+    pub fn into_inner(self) -> S {
+        self.state
+    }
+}
+
+impl<S, C> Clone for FnState<'_, S, C>
+where
+    S: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            state: self.state.clone(),
+            problem: self.problem,
+        }
+    }
+}
+
+impl<S, C> PartialEq for FnState<'_, S, C>
+where
+    S: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.state == other.state
+    }
+}
+
+impl<S, C> Eq for FnState<'_, S, C> where S: Eq {}
+
+impl<S, C> Hash for FnState<'_, S, C>
+where
+    S: Hash,
+{
+    fn hash<H: Hasher>(&self, hasher: &mut H) {
+        self.state.hash(hasher);
+    }
+}
+
+impl<S, C> fmt::Debug for FnState<'_, S, C>
+where
+    S: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.state.fmt(f)
+    }
+}
+
+impl<S, C> SolutionIdentifiable for FnState<'_, S, C> {
+    fn is_solution(&self) -> bool {
+        (self.problem.goal)(&self.state)
+    }
+}
+
+impl<S, C> Scoreable for FnState<'_, S, C>
+where
+    C: Ord,
+{
+    type Score = C;
+
+    fn score(&self) -> Self::Score {
+        (self.problem.heuristic)(&self.state)
+    }
+}
+
+impl<S, C> FocalScoreable for FnState<'_, S, C>
+where
+    C: Ord,
+{
+    type FocalScore = C;
+
+    fn focal_score(&self) -> Self::FocalScore {
+        (self.problem.focal_heuristic)(&self.state)
+    }
+}
+
+impl<S, C> CostSearchable for FnState<'_, S, C>
+where
+    C: Ord,
+{
+    type Cost = C;
+
+    fn next_states_with_costs(&self) -> impl Iterator<Item = (Self, Self::Cost)> {
+        (self.problem.successors)(&self.state)
+            .into_iter()
+            .map(|(state, cost)| (self.problem.state(state), cost))
+    }
+}
+
+impl<S, C> ReverseCostSearchable for FnState<'_, S, C>
+where
+    C: Ord,
+{
+    fn previous_states_with_costs(&self) -> impl Iterator<Item = (Self, Self::Cost)> {
+        (self.problem.predecessors)(&self.state)
+            .into_iter()
+            .map(|(state, cost)| (self.problem.state(state), cost))
+    }
+
+    fn reverse_score(&self) -> Self::Score {
+        (self.problem.reverse_heuristic)(&self.state)
+    }
+}
+
+#[test]
+fn test() {
+    use crate::*;
+
+    let neighbours = |&(x, y): &(i32, i32)| [(x - 1, y), (x, y - 1), (x + 1, y), (x, y + 1)];
+    let problem = FnProblem::new(neighbours, |&state| state == (3, 3))
+        .with_heuristic(|&(x, y)| (x.abs_diff(3) + y.abs_diff(3)) as usize);
+
+    let mut searcher: Searcher<search::unguided::no_route::hashable::Manager<_>> =
+        Searcher::new(problem.state((0, 0)));
+    assert_eq!(searcher.next().map(FnState::into_inner), Some((3, 3)));
+
+    let mut searcher: Searcher<search::a_star::route::hashable::Manager<_>> =
+        Searcher::new(problem.state((0, 0)));
+    let route = searcher.next().unwrap();
+    assert_eq!(route.total_cost, 6);
+    assert_eq!(route.states.first().unwrap().state, (0, 0));
+    assert_eq!(route.states.last().unwrap().state, (3, 3));
+}
+
+#[cfg(feature = "parallel")]
+#[test]
+fn test_parallel() {
+    use crate::*;
+
+    let neighbours = |&(x, y): &(i32, i32)| [(x - 1, y), (x, y - 1), (x + 1, y), (x, y + 1)];
+    let problem = FnProblem::new(neighbours, |&state| state == (3, 3))
+        .with_heuristic(|&(x, y)| (x.abs_diff(3) + y.abs_diff(3)) as usize);
+
+    let mut searcher: Searcher<search::unguided::no_route::parallel::Manager<_>> =
+        Searcher::new(problem.state((0, 0)));
+    assert_eq!(searcher.next().map(FnState::into_inner), Some((3, 3)));
+
+    let mut searcher: Searcher<search::unguided::route::parallel::Manager<_>> =
+        Searcher::new(problem.state((0, 0)));
+    assert_eq!(searcher.next().map(|route| route.len()), Some(7));
+
+    let mut searcher: Searcher<search::a_star::parallel::Manager<_>> =
+        Searcher::new(problem.state((0, 0)));
+    searcher.manager.threads = 4;
+    let route = searcher.next().unwrap();
+    assert_eq!(route.total_cost, 6);
+    assert_eq!(route.states.first().unwrap().state, (0, 0));
+    assert_eq!(route.states.last().unwrap().state, (3, 3));
+}
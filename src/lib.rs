@@ -4,13 +4,18 @@
 //!
 //! Implement [`Searchable`] + [`SolutionIdentifiable`] to perform breadth-first or depth-first searching. Implement [`Scoreable`] as well to perform heuristically guided search space exploration. Finally, additionally implement [`CostSearchable`] to perform A* based search exploration. Pass them to [`Searcher`] to create an iterator that will search for a solution.
 //!
-//! [`Searcher`] requires that you specify a `Manager` type that determines the strategy, return result, and optimization of the search algorithm. Choose one of the searchers defined in the hierarchy of the [`search`] module to fit your individual needs.
+//! [`Searcher`] requires that you specify a `Manager` type that determines the strategy, return result, and optimization of the search algorithm. Choose one of the searchers defined in the hierarchy of the [`search`] module to fit your individual needs. The searcher keeps [`SearchStats`] describing the work performed so far in [`Searcher::stats`], useful for tuning heuristics. Set [`Searcher::max_expansions`] or [`Searcher::deadline`] to bound the work a search may perform; depth is only bounded by the `depth_limited` and `iterative_deepening` unguided managers, which track the depth of every state, and cost only by the `max_cost` of the `a_star` `route` and `no_route` managers. The searcher itself has no depth or cost limit. Call [`Searcher::try_next`] in place of [`Iterator::next`] to find out whether a search stopped because the search space was exhausted or because a limit was reached. To stop a search running on a background thread, give it a [`CancellationToken`] through [`Searcher::cancellation`] and cancel the token from another thread. Implement [`SearchObserver`] and attach it with [`Searcher::with_observer`] to be notified of every state the search pops, expands, generates or discards, for progress reporting, logging or visualization. To interleave a search with other work, call [`Searcher::step`] to perform one state expansion at a time. To search the same search space for different goals, identify solutions with a predicate attached through [`Searcher::with_goal_predicate`] in place of implementing [`SolutionIdentifiable`], with any manager other than the `bidirectional` ones, which search towards a known goal state, and choose [`FnHeuristic`] as the heuristic of a guided or A* based manager to supply a heuristic function specific to the goal through [`Searcher::new_with_heuristic`], in place of implementing [`Scoreable`]. To search from many initial states at once, such as for shortest paths from multiple sources or for the nearest of several starting points, create the searcher with [`Searcher::new_multi`], or [`Searcher::new_multi_with_heuristic`] along with a heuristic function; routes then begin with the initial state they were found from.
 //!
-//! * Implement [`Scoreable`] to utilize the `guided` search strategy based managers, which will prioritize searching states with a lower associated score first. Additionally, implement [`CostSearchable`] to make use of the A* based search managers in the `a_star` module. If implementing [`Scoreable`] is too complex or unnecessary for your use case, then you may use the `unguided` search managers, which explore the space naively in a depth-first or breadth-first manner, toggleable by a flag on the manager itself.
-//! * Use a `route` based manager to yield results consisting of the sequence of steps taken from the starting state to the ending state. Use a `no_route` manager to just yield the solution state alone. Route based managers require that your state type implement [`Clone`].
+//! * Implement [`Scoreable`] to utilize the `guided` search strategy based managers, which will prioritize searching states with a lower associated score first. The `beam` guided managers only keep a bounded number of the best scoring states per depth layer, trading completeness for bounded memory usage. Additionally, implement [`CostSearchable`] to make use of the A* based search managers in the `a_star` module, or the iterative-deepening A* managers in the `ida_star` module if the search space is too large to keep in memory. To trade optimality for speed with a guaranteed bound, set the `weight` of an A* manager to perform weighted A* search, or implement [`FocalScoreable`] to use the focal search managers in `a_star::focal`. The anytime managers in `a_star::anytime` yield a sequence of solutions of decreasing cost, starting with one found quickly. If your search space has no meaningful heuristic but its transitions have costs, implement [`UniformCostSearchable`] to use the uniform-cost (Dijkstra) based managers in the `uniform_cost` module, which yield the cheapest solution first. If implementing [`Scoreable`] is too complex or unnecessary for your use case, then you may use the `unguided` search managers, which explore the space naively in a depth-first or breadth-first manner, toggleable by a flag on the manager itself. The `depth_limited` and `iterative_deepening` unguided managers instead perform depth-first search with a bounded depth, the latter yielding the shortest solutions like breadth-first search while only keeping the current path in memory.
+//! * If your search space has a single known goal state, implement [`ReverseSearchable`] as well to use the `bidirectional` managers, which search forwards from the initial state and backwards from the goal state at the same time. Implement [`ReverseCostSearchable`] in place of it to use the bidirectional A* manager, which yields a cost-optimal [`CostRoute`]. Create them with [`Searcher::new_with_goal`], or implement [`ReverseUniformCostSearchable`] in place of [`ReverseCostSearchable`] and create the bidirectional A* manager with [`Searcher::new_with_goal_and_heuristics`] to supply a heuristic function for each direction.
+//! * Use a `route` based manager to yield results consisting of the sequence of steps taken from the starting state to the ending state. Use a `no_route` manager to just yield the solution state alone. Route based managers require that your state type implement [`Clone`]. Each explored state is stored once, shared between the fringe and the parent links of the routes through it, and is only cloned to build the routes yielded. The A* based route managers yield a [`CostRoute`], which additionally carries the cost of each step and the total cost of the route. If the transitions of your search space are naturally described as actions, implement [`ActionSearchable`] in place of [`Searchable`] and use an unguided `action_route` manager to yield an [`ActionRoute`], consisting of the actions taken along with the states they lead to.
 //! * Implement [`Eq`] + [`std::hash::Hash`] + [`Clone`] for your [`Searchable`] type to benefit from prior explored state checking optimization using a `hashable` manager; if youre unable to, then use an `unhashable` manager, which does not require these additional bounds, but will likely explore the space much less efficiently unless cyclic traversal is not an inherent property of your search space.
+//! * If you are unable to implement these traits for your state type, such as for tuples or types from other crates, describe your search space with closures using [`FnProblem`] instead, and search from the [`FnState`] returned by [`FnProblem::state`] with any manager other than the `action_route` managers and the unguided `external` manager. The closures must be `Send` + `Sync` to be shared between the threads of the `parallel` managers, and searches over these states cannot be saved with `Searcher::save`.
+//! * Enable the `parallel` cargo feature to use the `a_star::parallel` manager, which spreads an A* search over multiple threads by partitioning the search space between them by the hash of each state, and the unguided `parallel` managers, which perform breadth-first search by generating the successors of the states of a depth layer concurrently on the rayon thread pool. These managers require your state and cost types to implement `Send` + `Sync`.
+//! * Enable the `serde` cargo feature to make [`Searcher`] and every manager other than the unguided `external` manager serializable, to checkpoint long running searches and resume them later. With your state types implementing `serde::Serialize` + `serde::Deserialize`, save a searcher in a compact binary format with `Searcher::save`, and resume it with `Searcher::load`, or with `Searcher::load_with_heuristic` for a search guided by a heuristic function.
+//! * Enable the `external` cargo feature to use the unguided `external` manager, which performs breadth-first search over state spaces too large to fit in memory, by keeping the fringe and explored states in files on disk and culling duplicate states a whole layer at a time. This manager requires your state type to implement `Ord` + `serde::Serialize` + `serde::Deserialize`. Should reading or writing its files fail, the search stops and [`Searcher::try_next`] reports [`SearchOutcome::Failed`].
 //!
-//! When implementing [`Scoreable`], make sure that lower scoring states are closer to a solution.
+//! When implementing [`Scoreable`], make sure that lower scoring states are closer to a solution. When implementing [`CostSearchable`], the type of the traversal costs may differ from the type of the heuristic scores, as long as scores can be converted into costs with [`From`].
 //!
 //! ```
 //! use space_search::*;
@@ -38,14 +43,29 @@
 //!     }
 //! }
 //!
-//! let mut searcher: Searcher<search::unguided::no_route::hashable::Manager<_>, _> = Searcher::new(Pos(0, 0));
+//! let mut searcher: Searcher<search::unguided::no_route::hashable::Manager<_>> = Searcher::new(Pos(0, 0));
 //! assert_eq!(searcher.next(), Some(Pos(5, 5)));
 //! ```
 
-use std::collections::VecDeque;
+use std::{
+    cell::Cell,
+    collections::{BinaryHeap, VecDeque},
+    mem,
+    ops::{Add, Sub},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    time::Instant,
+};
 
+mod fn_problem;
+mod nodes;
 pub mod search;
 
+pub use fn_problem::{FnProblem, FnState};
+use nodes::Nodes;
+
 /// Basic trait for depth-first and breadth-first search space exploration.
 ///
 /// Implement this + [`SolutionIdentifiable`] for your state type to perform search-space exploration.
@@ -54,6 +74,27 @@ pub trait Searchable: Sized {
     fn next_states(&self) -> impl Iterator<Item = Self>;
 }
 
+/// Trait for search space exploration where transitions between states are labelled with actions.
+///
+/// Implement this + [`SolutionIdentifiable`] for your state type to perform search-space exploration
+/// yielding the sequence of actions taken to reach a solution.
+pub trait ActionSearchable: Sized {
+    /// Type used to represent an action taken to move from one state to another.
+    type Action;
+
+    /// Yield all adjacent explorable states reachable from this state, paired with the action taken
+    /// to reach each new state.
+    fn next_actions(&self) -> impl Iterator<Item = (Self::Action, Self)>;
+}
+
+/// Trait for search spaces that can also be explored backwards, from a known goal state.
+///
+/// Implement this + [`Searchable`] for your state type to perform bidirectional search-space exploration.
+pub trait ReverseSearchable: Searchable {
+    /// Yield all states from which this state is directly reachable.
+    fn previous_states(&self) -> impl Iterator<Item = Self>;
+}
+
 /// Trait that allows a state to be identified as a solution.
 ///
 /// Implement this + [`Searchable`] for your state type to perform search-space exploration.
@@ -80,15 +121,133 @@ pub trait Scoreable {
     fn score(&self) -> Self::Score;
 }
 
+/// Source of the heuristic scores guiding the guided and A* based managers, chosen through their `H`
+/// type parameter.
+///
+/// Managers default to [`ScoreableHeuristic`], scoring states with [`Scoreable::score`]. Choose
+/// [`FnHeuristic`] instead and create the searcher with [`Searcher::new_with_heuristic`] to supply a
+/// heuristic function, such as one specific to the goal being searched for, or for states that do not
+/// implement [`Scoreable`] at all.
+pub trait Heuristic<S>: Default {
+    /// Type used to represent a state's score.
+    type Score: Ord;
+
+    /// Score the state; lower scoring states are explored first.
+    fn score(&self, state: &S) -> Self::Score;
+}
+
+/// Heuristic scoring states with [`Scoreable::score`]; the default heuristic of every guided and A*
+/// based manager.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct ScoreableHeuristic;
+
+impl<S> Heuristic<S> for ScoreableHeuristic
+where
+    S: Scoreable,
+{
+    type Score = S::Score;
+
+    fn score(&self, state: &S) -> Self::Score {
+        state.score()
+    }
+}
+
+/// Heuristic scoring states with [`ReverseCostSearchable::reverse_score`]; the default heuristic of
+/// the bidirectional A* manager when searching backwards from the goal state.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct ReverseScoreableHeuristic;
+
+impl<S> Heuristic<S> for ReverseScoreableHeuristic
+where
+    S: ReverseCostSearchable,
+{
+    type Score = S::Score;
+
+    fn score(&self, state: &S) -> Self::Score {
+        state.reverse_score()
+    }
+}
+
+/// Heuristic function supplied through [`Searcher::new_with_heuristic`] or
+/// [`Searcher::new_multi_with_heuristic`].
+///
+/// Until a function is supplied, such as when the searcher is created with [`Searcher::new`] or
+/// loaded with `Searcher::load` in place of `Searcher::load_with_heuristic`, every state scores
+/// `C::default()`, leaving A* based managers to explore in order of cumulative cost alone.
+pub struct FnHeuristic<S, C>(Option<HeuristicFn<S, C>>);
+
+/// Internal.
+///
+/// Function scoring states within a [`FnHeuristic`].
+type HeuristicFn<S, C> = Box<dyn Fn(&S) -> C + Send + Sync>;
+
+impl<S, C> Default for FnHeuristic<S, C> {
+    fn default() -> Self {
+        Self(None)
+    }
+}
+
+impl<S, C> Heuristic<S> for FnHeuristic<S, C>
+where
+    C: Ord + Default,
+{
+    type Score = C;
+
+    fn score(&self, state: &S) -> Self::Score {
+        match &self.0 {
+            Some(heuristic) => heuristic(state),
+            None => C::default(),
+        }
+    }
+}
+
+/// Trait providing a secondary heuristic for bounded-suboptimal focal search.
+///
+/// Implement this + [`CostSearchable`] to perform focal search-space exploration, or this +
+/// [`UniformCostSearchable`] to perform it with a heuristic function supplied through [`FnHeuristic`].
+///
+/// Among all states whose estimated total cost is within the manager's suboptimality factor of the
+/// lowest estimate, the state with the lowest focal score is explored first. The focal score need not
+/// be admissible; a common choice is an estimate of the number of remaining steps to a solution.
+pub trait FocalScoreable {
+    /// Type used to represent a state's focal score.
+    type FocalScore: Ord;
+
+    /// Secondary score function used to choose between states within the suboptimality bound.
+    fn focal_score(&self) -> Self::FocalScore;
+}
+
+/// Trait for search space exploration ordered by the cumulative cost of reaching each state.
+///
+/// Implement this + [`SolutionIdentifiable`] to perform uniform-cost search-space exploration.
+///
+/// [`Searchable`] is automatically implemented if this trait is implemented, and this trait is
+/// automatically implemented if [`CostSearchable`] is implemented.
+pub trait UniformCostSearchable: Sized {
+    /// Type used to represent the cost of traversing between states.
+    /// Common types can be [`i32`], [`usize`], an ordered float type, or your own type implementing [`Ord`].
+    type Cost: Ord;
+
+    /// Yield all adjacent explorable states reachable from this state, paired with the associated cost
+    /// of traversing from the current state each new state.
+    fn next_states_with_costs(&self) -> impl Iterator<Item = (Self, Self::Cost)>;
+}
+
 /// Trait for search space exploration guided by a cost function & heuristic.
 ///
 /// Implement this + [`Scoreable`] + [`SolutionIdentifiable`] to perform A* guided search-space exploration.
 ///
-/// [`Searchable`] is automatically implemented if this trait is implemented.
+/// [`Searchable`] and [`UniformCostSearchable`] are automatically implemented if this trait is implemented.
 pub trait CostSearchable: Scoreable + Sized {
+    /// Type used to represent the cost of traversing between states, which need not be the same
+    /// type as the heuristic [`Scoreable::Score`]; heuristic scores are converted into costs when
+    /// estimating the total cost of a route through a state.
+    /// Common types can be [`i32`], [`usize`], an ordered float type, or your own type implementing [`Ord`].
+    type Cost: Ord + From<Self::Score>;
+
     /// Yield all adjacent explorable states reachable from this state, paired with the associated cost
     /// of traversing from the current state each new state.
-    fn next_states_with_costs(&self) -> impl Iterator<Item = (Self, Self::Score)>;
+    fn next_states_with_costs(&self) -> impl Iterator<Item = (Self, Self::Cost)>;
 }
 
 // this doesnt work unfortunately :(
@@ -101,50 +260,93 @@ pub trait CostSearchable: Scoreable + Sized {
 // impl<T> CostSearchable for T
 // where
 //     T: Scoreable + Searchable + CostSearchableForSearchable,
-//     T::Score: One,
+//     T::Cost: One,
 // {
-//     fn next_states_with_costs(&self) -> impl Iterator<Item = (Self, Self::Score)> {
-//         self.next_states().map(|s| (s, T::Score::one()))
+//     fn next_states_with_costs(&self) -> impl Iterator<Item = (Self, Self::Cost)> {
+//         self.next_states().map(|s| (s, T::Cost::one()))
 //     }
 // }
 
-impl<T> Searchable for T
+impl<T> UniformCostSearchable for T
 where
     T: CostSearchable,
+{
+    type Cost = T::Cost;
+
+    fn next_states_with_costs(&self) -> impl Iterator<Item = (Self, Self::Cost)> {
+        CostSearchable::next_states_with_costs(self)
+    }
+}
+
+impl<T> Searchable for T
+where
+    T: UniformCostSearchable,
 {
     fn next_states(&self) -> impl Iterator<Item = Self> {
         self.next_states_with_costs().map(|(s, _)| s)
     }
 }
 
-/// Internal.
+/// Trait for search space exploration guided by a cost function & heuristic, in both directions
+/// between the initial state and a known goal state.
 ///
-/// Used to represent states paired with their scores in guided exploration managers.
-struct OrderedSearchable<T, C> {
-    state: T,
-    score: C,
+/// Implement this + [`CostSearchable`] to perform bidirectional A* guided search-space exploration.
+///
+/// [`ReverseSearchable`] and [`ReverseUniformCostSearchable`] are automatically implemented if this
+/// trait is implemented.
+pub trait ReverseCostSearchable: CostSearchable {
+    /// Yield all states from which this state is directly reachable, paired with the associated cost
+    /// of traversing from each of those states to the current state.
+    fn previous_states_with_costs(&self) -> impl Iterator<Item = (Self, Self::Cost)>;
+
+    /// Score function used for heuristic exploration backwards from the goal state; the counterpart of
+    /// [`Scoreable::score`]. Ensure the scores returned by this function decrease with the proximity
+    /// to the initial state.
+    fn reverse_score(&self) -> Self::Score;
+}
+
+/// Trait for search space exploration by cost in both directions between the initial state and a
+/// known goal state, without a heuristic of its own.
+///
+/// Implement this + [`UniformCostSearchable`] to use the bidirectional A* manager with heuristic
+/// functions supplied through [`Searcher::new_with_goal_and_heuristics`], in place of implementing
+/// [`ReverseCostSearchable`].
+///
+/// [`ReverseSearchable`] is automatically implemented if this trait is implemented, and this trait is
+/// automatically implemented if [`ReverseCostSearchable`] is implemented.
+pub trait ReverseUniformCostSearchable: UniformCostSearchable {
+    /// Yield all states from which this state is directly reachable, paired with the associated cost
+    /// of traversing from each of those states to the current state.
+    fn previous_states_with_costs(&self) -> impl Iterator<Item = (Self, Self::Cost)>;
 }
 
-impl<S> From<S> for OrderedSearchable<S, S::Score>
+impl<T> ReverseUniformCostSearchable for T
 where
-    S: Scoreable,
+    T: ReverseCostSearchable,
 {
-    fn from(state: S) -> Self {
-        let score = state.score();
-        OrderedSearchable { state, score }
+    fn previous_states_with_costs(&self) -> impl Iterator<Item = (Self, Self::Cost)> {
+        ReverseCostSearchable::previous_states_with_costs(self)
     }
 }
 
-impl<S> From<StateParent<S>> for OrderedSearchable<StateParent<S>, S::Score>
+impl<T> ReverseSearchable for T
 where
-    S: Scoreable,
+    T: ReverseUniformCostSearchable,
 {
-    fn from(pair: StateParent<S>) -> Self {
-        let score = pair.state.score();
-        OrderedSearchable { state: pair, score }
+    fn previous_states(&self) -> impl Iterator<Item = Self> {
+        self.previous_states_with_costs().map(|(s, _)| s)
     }
 }
 
+/// Internal.
+///
+/// Used to represent states paired with their scores in guided exploration managers.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+struct OrderedSearchable<T, C> {
+    state: T,
+    score: C,
+}
+
 impl<T, C> PartialEq for OrderedSearchable<T, C>
 where
     C: PartialEq,
@@ -174,9 +376,15 @@ where
     }
 }
 
+/// Internal.
+///
+/// Fringe of states ordered by their associated scores, lowest first.
+type ScoredFringe<T, C> = BinaryHeap<OrderedSearchable<T, C>>;
+
 /// Internal.
 ///
 /// Used to represent states with no additional context in solution-only yielding managers.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct NoContext<S>(S);
 
 impl<S> AsRef<S> for NoContext<S> {
@@ -185,13 +393,23 @@ impl<S> AsRef<S> for NoContext<S> {
     }
 }
 
+impl<S> SolutionIdentifiable for NoContext<S>
+where
+    S: SolutionIdentifiable,
+{
+    fn is_solution(&self) -> bool {
+        self.0.is_solution()
+    }
+}
+
 /// Internal.
 ///
 /// Used to represent states with the added context of their parent state
 /// in solution-route yielding managers.
 #[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct StateParent<S> {
-    state: S,
+    state: Arc<S>,
     parent: Option<usize>,
 }
 
@@ -201,10 +419,73 @@ impl<S> AsRef<S> for StateParent<S> {
     }
 }
 
+impl<S> SolutionIdentifiable for StateParent<S>
+where
+    S: SolutionIdentifiable,
+{
+    fn is_solution(&self) -> bool {
+        self.state.is_solution()
+    }
+}
+
+/// Internal.
+///
+/// Used to represent states with the added context of their parent state and the action
+/// taken to reach them in action-route yielding managers.
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct StateParentAction<S, A> {
+    state: Arc<S>,
+    parent: Option<usize>,
+    action: Option<A>,
+}
+
+impl<S, A> AsRef<S> for StateParentAction<S, A> {
+    fn as_ref(&self) -> &S {
+        &self.state
+    }
+}
+
+impl<S, A> SolutionIdentifiable for StateParentAction<S, A>
+where
+    S: SolutionIdentifiable,
+{
+    fn is_solution(&self) -> bool {
+        self.state.is_solution()
+    }
+}
+
+/// Internal.
+///
+/// Used to represent states with the added context of their depth in the current path
+/// in depth-limited and iterative-deepening managers.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct StateDepth<S> {
+    state: S,
+    depth: usize,
+}
+
+impl<S> AsRef<S> for StateDepth<S> {
+    fn as_ref(&self) -> &S {
+        &self.state
+    }
+}
+
+impl<S> SolutionIdentifiable for StateDepth<S>
+where
+    S: SolutionIdentifiable,
+{
+    fn is_solution(&self) -> bool {
+        self.state.is_solution()
+    }
+}
+
 /// Internal.
 ///
 /// Used to represent states with the added context of their
 /// cumulative rolling cost for A* based managers.
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct StateCumulativeCost<S, C> {
     state: S,
     cumulative_cost: C,
@@ -216,13 +497,23 @@ impl<S, C> AsRef<S> for StateCumulativeCost<S, C> {
     }
 }
 
+impl<S, C> SolutionIdentifiable for StateCumulativeCost<S, C>
+where
+    S: SolutionIdentifiable,
+{
+    fn is_solution(&self) -> bool {
+        self.state.is_solution()
+    }
+}
+
 /// Internal.
 ///
 /// Used to represent states with the added context of their parent state &
 /// cumulative rolling cost for A* based solution route-yielding managers.
 #[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct StateParentCumulativeCost<S, C> {
-    state: S,
+    state: Arc<S>,
     parent: Option<usize>,
     cumulative_cost: C,
 }
@@ -233,6 +524,192 @@ impl<S, C> AsRef<S> for StateParentCumulativeCost<S, C> {
     }
 }
 
+impl<S, C> SolutionIdentifiable for StateParentCumulativeCost<S, C>
+where
+    S: SolutionIdentifiable,
+{
+    fn is_solution(&self) -> bool {
+        self.state.is_solution()
+    }
+}
+
+/// Internal.
+///
+/// Used to represent states with the added context of their depth in the current path &
+/// cumulative rolling cost for iterative-deepening A* based managers.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct StateDepthCumulativeCost<S, C> {
+    state: S,
+    depth: usize,
+    cumulative_cost: C,
+}
+
+impl<S, C> AsRef<S> for StateDepthCumulativeCost<S, C> {
+    fn as_ref(&self) -> &S {
+        &self.state
+    }
+}
+
+impl<S, C> SolutionIdentifiable for StateDepthCumulativeCost<S, C>
+where
+    S: SolutionIdentifiable,
+{
+    fn is_solution(&self) -> bool {
+        self.state.is_solution()
+    }
+}
+
+/// Internal.
+///
+/// Used to represent states with the added context of their parent state & search direction
+/// in bidirectional managers. States where both searches meet additionally carry their parent
+/// state on the opposite side, and are identified as solutions.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DirectedStateParent<S> {
+    state: Arc<S>,
+    parent: Option<usize>,
+    backward: bool,
+    meeting: Option<Option<usize>>,
+}
+
+impl<S> AsRef<S> for DirectedStateParent<S> {
+    fn as_ref(&self) -> &S {
+        &self.state
+    }
+}
+
+impl<S> SolutionIdentifiable for DirectedStateParent<S> {
+    fn is_solution(&self) -> bool {
+        self.meeting.is_some()
+    }
+}
+
+/// Internal.
+///
+/// Used to represent states with the added context of their parent state, search direction &
+/// cumulative rolling cost in bidirectional A* based managers. States where both searches meet
+/// additionally carry their parent state & cumulative cost on the opposite side, and are identified
+/// as solutions; their own cumulative cost is then the total cost of the route through them.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DirectedStateParentCumulativeCost<S, C> {
+    state: Arc<S>,
+    parent: Option<usize>,
+    backward: bool,
+    cumulative_cost: C,
+    meeting: Option<(Option<usize>, C)>,
+}
+
+impl<S, C> AsRef<S> for DirectedStateParentCumulativeCost<S, C> {
+    fn as_ref(&self) -> &S {
+        &self.state
+    }
+}
+
+impl<S, C> SolutionIdentifiable for DirectedStateParentCumulativeCost<S, C> {
+    fn is_solution(&self) -> bool {
+        self.meeting.is_some()
+    }
+}
+
+/// Internal.
+///
+/// Used to pair items popped from the explored nodes of route yielding managers with the index of
+/// their node, so that the node need not be looked up again when expanding them. Generated successor
+/// items have no node until they are placed.
+pub struct NodeItem<T> {
+    item: T,
+    node: Option<usize>,
+}
+
+impl<S, T> AsRef<S> for NodeItem<T>
+where
+    T: AsRef<S>,
+{
+    fn as_ref(&self) -> &S {
+        self.item.as_ref()
+    }
+}
+
+impl<T> SolutionIdentifiable for NodeItem<T>
+where
+    T: SolutionIdentifiable,
+{
+    fn is_solution(&self) -> bool {
+        self.item.is_solution()
+    }
+}
+
+/// Internal.
+///
+/// Used to pair items popped by the parallel breadth-first managers with their successors, which are
+/// generated concurrently for many states of a depth layer ahead of the states being popped. The
+/// successors are taken, rather than cloned, when the searcher expands the item.
+#[cfg_attr(not(feature = "parallel"), allow(dead_code))]
+pub struct SuccessorsItem<T, S> {
+    item: T,
+    successors: Cell<Vec<S>>,
+}
+
+impl<S, T> AsRef<S> for SuccessorsItem<T, S>
+where
+    T: AsRef<S>,
+{
+    fn as_ref(&self) -> &S {
+        self.item.as_ref()
+    }
+}
+
+impl<T, S> SolutionIdentifiable for SuccessorsItem<T, S>
+where
+    T: SolutionIdentifiable,
+{
+    fn is_solution(&self) -> bool {
+        self.item.is_solution()
+    }
+}
+
+/// Internal.
+///
+/// Used to represent the cheapest solution found by managers that identify solutions on their own
+/// behalf, paired with its cumulative cost.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(not(feature = "parallel"), allow(dead_code))]
+pub struct Incumbent<S, C> {
+    state: S,
+    cumulative_cost: C,
+}
+
+impl<S, C> AsRef<S> for Incumbent<S, C> {
+    fn as_ref(&self) -> &S {
+        &self.state
+    }
+}
+
+impl<S, C> SolutionIdentifiable for Incumbent<S, C> {
+    fn is_solution(&self) -> bool {
+        true
+    }
+}
+
+/// Internal.
+///
+/// Marker for fringe items that goal predicates may identify as solutions. Not implemented by
+/// [`Incumbent`], as managers yielding it identify solutions themselves, nor by
+/// [`DirectedStateParent`] and [`DirectedStateParentCumulativeCost`], as bidirectional managers
+/// only yield the states where both directions meet, so that goal predicates cannot be attached
+/// to searchers whose managers would not consult them.
+pub trait PredicateIdentifiable {}
+
+impl<S> PredicateIdentifiable for NoContext<S> {}
+impl<S> PredicateIdentifiable for StateParent<S> {}
+impl<S, A> PredicateIdentifiable for StateParentAction<S, A> {}
+impl<S> PredicateIdentifiable for StateDepth<S> {}
+impl<S, C> PredicateIdentifiable for StateCumulativeCost<S, C> {}
+impl<S, C> PredicateIdentifiable for StateParentCumulativeCost<S, C> {}
+impl<S, C> PredicateIdentifiable for StateDepthCumulativeCost<S, C> {}
+impl<T> PredicateIdentifiable for NodeItem<T> where T: PredicateIdentifiable {}
+impl<T, S> PredicateIdentifiable for SuccessorsItem<T, S> where T: PredicateIdentifiable {}
+
 impl<S, C> From<StateParentCumulativeCost<S, C>> for StateParent<S> {
     fn from(
         StateParentCumulativeCost { state, parent, .. }: StateParentCumulativeCost<S, C>,
@@ -251,10 +728,16 @@ pub trait ExplorationManager {
     type CurrentStateContext;
     type NextStatesIterItem;
 
-    fn initialize(initial_state: Self::State) -> Self;
+    fn initialize(initial_states: Vec<Self::State>) -> Self;
     fn pop_state(&mut self) -> Option<Self::FringeItem>;
+    fn pop_state_within(&mut self, _budget: &mut Budget) -> Option<Self::FringeItem> {
+        self.pop_state()
+    }
     fn prepare_result_from(&self, item: Self::FringeItem) -> Self::YieldResult;
     fn valid_state(&mut self, item: &Self::FringeItem) -> bool;
+    fn exceeds_limit(&mut self, _item: &Self::FringeItem) -> bool {
+        false
+    }
     fn place_state(&mut self, item: Self::FringeItem);
     fn register_current_state(&mut self, item: &Self::FringeItem) -> Self::CurrentStateContext;
     fn prepare_state(
@@ -263,15 +746,348 @@ pub trait ExplorationManager {
         state: Self::NextStatesIterItem,
     ) -> Self::FringeItem;
     fn next_states_iter(
-        current_state: &Self::State,
+        current_state: &Self::FringeItem,
     ) -> impl Iterator<Item = Self::NextStatesIterItem>;
+    fn fringe_size(&self) -> usize;
+    fn explored_size(&self) -> usize;
+    fn limit_reached(&self) -> Option<SearchLimit> {
+        None
+    }
+    fn failed(&self) -> bool {
+        false
+    }
+}
+
+/// Internal.
+///
+/// Limits on the work a manager may perform on its own behalf within a single call to
+/// [`ExplorationManager::pop_state_within`], such as to expand states on multiple threads, along with
+/// a record of the work it performed. The default budget is unlimited.
+#[derive(Debug, Default)]
+pub struct Budget {
+    deadline: Option<Instant>,
+    cancellation: Option<CancellationToken>,
+    expansions: Option<usize>,
+    expanded: usize,
+    generated: usize,
+    interrupted: bool,
+}
+
+impl Budget {
+    /// Return `true` if the searcher's deadline has passed or its search has been cancelled.
+    #[cfg_attr(not(feature = "parallel"), allow(dead_code))]
+    pub(crate) fn spent(&self) -> bool {
+        self.cancellation
+            .as_ref()
+            .is_some_and(CancellationToken::is_cancelled)
+            || self
+                .deadline
+                .is_some_and(|deadline| Instant::now() >= deadline)
+    }
+
+    /// Return the number of states the manager may expand, if limited.
+    #[cfg_attr(not(feature = "parallel"), allow(dead_code))]
+    pub(crate) fn expansions(&self) -> Option<usize> {
+        self.expansions
+    }
+
+    /// Record the states expanded and successors generated by the manager, and whether it stopped
+    /// because the budget ran out before the search could finish.
+    #[cfg_attr(not(feature = "parallel"), allow(dead_code))]
+    pub(crate) fn record(&mut self, expanded: usize, generated: usize, interrupted: bool) {
+        self.expanded += expanded;
+        self.generated += generated;
+        self.interrupted |= interrupted;
+    }
+}
+
+/// Internal.
+///
+/// Trait for exploration managers that search towards a known goal state.
+pub trait GoalExplorationManager: ExplorationManager {
+    fn initialize_with_goal(initial_state: Self::State, goal_state: Self::State) -> Self;
+}
+
+/// Internal.
+///
+/// Trait for exploration managers that search towards a known goal state, guided by a [`FnHeuristic`]
+/// in each direction.
+pub trait GoalHeuristicExplorationManager: GoalExplorationManager {
+    type Score;
+
+    fn initialize_with_goal_and_heuristics(
+        initial_state: Self::State,
+        goal_state: Self::State,
+        heuristic: FnHeuristic<Self::State, Self::Score>,
+        reverse_heuristic: FnHeuristic<Self::State, Self::Score>,
+    ) -> Self;
+
+    fn set_heuristics(
+        &mut self,
+        heuristic: FnHeuristic<Self::State, Self::Score>,
+        reverse_heuristic: FnHeuristic<Self::State, Self::Score>,
+    );
+}
+
+/// Internal.
+///
+/// Trait for exploration managers guided by a [`FnHeuristic`], supplied in place of [`Scoreable`].
+pub trait HeuristicExplorationManager: ExplorationManager {
+    type Score;
+
+    fn initialize_with_heuristic(
+        initial_states: Vec<Self::State>,
+        heuristic: FnHeuristic<Self::State, Self::Score>,
+    ) -> Self;
+
+    fn set_heuristic(&mut self, heuristic: FnHeuristic<Self::State, Self::Score>);
+}
+
+/// Statistics describing the amount of work performed by a [`Searcher`].
+///
+/// Updated by the searcher as it iterates; inspect [`Searcher::stats`] at any point to see how much
+/// of the search space has been explored so far.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SearchStats {
+    /// Number of states whose successors have been generated.
+    pub states_expanded: usize,
+
+    /// Number of successor states generated from expanded states.
+    pub states_generated: usize,
+
+    /// Number of generated successor states discarded by the manager for any reason other than a
+    /// depth or cost limit, usually because they had already been explored.
+    pub duplicates_culled: usize,
+
+    /// Number of generated successor states discarded by the manager for exceeding its depth or cost
+    /// limit, such as the `depth_limit` of the `depth_limited` managers or the `max_cost` of the A*
+    /// managers.
+    pub limits_culled: usize,
+
+    /// Largest number of states held in the manager's fringe at once.
+    pub peak_fringe_size: usize,
+
+    /// Number of states currently held in the manager's explored set, for managers that keep one.
+    pub explored_size: usize,
+
+    /// Number of solutions yielded by the searcher.
+    pub solutions_yielded: usize,
+}
+
+/// Limit that caused a search to stop before exploring the entire search space.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SearchLimit {
+    /// The searcher expanded [`Searcher::max_expansions`] states.
+    Expansions,
+
+    /// The searcher's [`Searcher::deadline`] passed.
+    Deadline,
+
+    /// The manager discarded states deeper than its depth limit.
+    /// Only reported by the `depth_limited` and `iterative_deepening` unguided managers, as no other
+    /// managers track the depth of states.
+    Depth,
+
+    /// The manager discarded states estimated to cost more than its cost limit.
+    /// Only reported by the `route` and `no_route` A* managers in the `a_star` module, as no other
+    /// managers have a cost limit.
+    Cost,
+}
+
+/// Solution route yielded by the A* based route managers, along with the costs of traversing it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CostRoute<S, C> {
+    /// Sequence of states taken from the initial state to the solution state.
+    pub states: Vec<S>,
+
+    /// Cost of each step along the route; `step_costs[i]` is the cost of traversing from
+    /// `states[i]` to `states[i + 1]`.
+    pub step_costs: Vec<C>,
+
+    /// Total cost of traversing the route.
+    pub total_cost: C,
+}
+
+/// Solution route yielded by the action-route managers, consisting of the actions taken along with the states they lead to.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ActionRoute<S, A> {
+    /// Sequence of states taken from the initial state to the solution state.
+    pub states: Vec<S>,
+
+    /// Sequence of actions taken; `actions[i]` is the action taken to move from `states[i]` to
+    /// `states[i + 1]`.
+    pub actions: Vec<A>,
+}
+
+/// Result of a single call to [`Searcher::step`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SearchStep<T> {
+    /// A state was taken from the fringe and expanded.
+    Expanded {
+        /// Number of successor states generated from the expanded state.
+        generated: usize,
+
+        /// Number of generated successor states kept by the manager for further exploration.
+        placed: usize,
+    },
+
+    /// A state was taken from the fringe and identified as a solution.
+    Solution(T),
+
+    /// The fringe was empty; no states remain to be explored.
+    FringeEmpty,
+}
+
+/// Handle used to stop a running search from another thread.
+///
+/// Clone the token, give one copy to a [`Searcher`] through [`Searcher::cancellation`], and call
+/// [`CancellationToken::cancel`] on another to stop the search before its next state expansion.
+/// The searcher's manager is left intact, so the search can be inspected, or resumed by removing the token.
+#[derive(Clone, Debug, Default)]
+pub struct CancellationToken {
+    cancelled: Arc<AtomicBool>,
+}
+
+impl CancellationToken {
+    /// Create a new token that has not been cancelled.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Request that all searches holding a copy of this token stop.
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+
+    /// Return `true` if this token has been cancelled.
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Relaxed)
+    }
+}
+
+/// Result of a single call to [`Searcher::try_next`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SearchOutcome<T> {
+    /// A solution was found.
+    Solution(T),
+
+    /// The search space was explored in its entirety; no further solutions exist.
+    Exhausted,
+
+    /// The search stopped early because a limit was reached; further solutions may exist beyond it.
+    LimitReached(SearchLimit),
+
+    /// The search was stopped through the searcher's [`CancellationToken`].
+    Cancelled,
+
+    /// The manager failed and cannot continue the search, such as when the `external` manager fails
+    /// to read or write its files; inspect the manager for the cause.
+    Failed,
+}
+
+/// Trait for observing the progress of a [`Searcher`].
+///
+/// Implement this to trace a search, for example to report progress, log or visualize the exploration,
+/// and attach it to a searcher with [`Searcher::with_observer`]. All methods do nothing by default.
+pub trait SearchObserver<S> {
+    /// Called when a state is taken from the fringe to be examined.
+    fn state_popped(&mut self, _state: &S) {}
+
+    /// Called once all successors of a state have been generated.
+    fn state_expanded(&mut self, _state: &S) {}
+
+    /// Called when a successor state is generated, before the manager decides whether to keep it.
+    fn successor_generated(&mut self, _state: &S) {}
+
+    /// Called when a generated successor state is discarded by the manager, usually because it had
+    /// already been explored.
+    fn successor_rejected(&mut self, _state: &S) {}
+
+    /// Called when a solution state is found, before its result is yielded.
+    fn solution_found(&mut self, _state: &S) {}
+}
+
+/// Search observer that ignores all events; the default observer of a [`Searcher`].
+#[derive(Clone, Copy, Debug, Default)]
+pub struct NoObserver;
+
+impl<S> SearchObserver<S> for NoObserver {}
+
+/// Trait for identifying solutions on behalf of a [`Searcher`].
+///
+/// Implemented by [`SolutionGoal`], which identifies solutions through [`SolutionIdentifiable`], and by
+/// any closure taking a state and returning `true` if it is a solution. Attach a closure to a searcher
+/// with [`Searcher::with_goal_predicate`] to search the same space for different goals without
+/// implementing [`SolutionIdentifiable`] for your state type.
+pub trait Goal<S, I> {
+    /// Return `true` if the fringe item is a solution.
+    fn is_goal(&self, item: &I) -> bool;
+}
+
+/// Goal that identifies solutions through [`SolutionIdentifiable`]; the default goal of a [`Searcher`].
+#[derive(Clone, Copy, Debug, Default)]
+pub struct SolutionGoal;
+
+impl<S, I> Goal<S, I> for SolutionGoal
+where
+    I: SolutionIdentifiable,
+{
+    fn is_goal(&self, item: &I) -> bool {
+        item.is_solution()
+    }
+}
+
+impl<S, I, F> Goal<S, I> for F
+where
+    I: AsRef<S> + PredicateIdentifiable,
+    F: Fn(&S) -> bool,
+{
+    fn is_goal(&self, item: &I) -> bool {
+        self(item.as_ref())
+    }
 }
 
 /// State space exploration iterator.
 ///
 /// Create an instance of this to explore a search space.
-pub struct Searcher<M> {
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(
+        serialize = "M: serde::Serialize",
+        deserialize = "M: serde::Deserialize<'de>, O: Default, G: Default"
+    ))
+)]
+pub struct Searcher<M, O = NoObserver, G = SolutionGoal> {
     pub manager: M,
+
+    /// Observer notified of every step taken by the search.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub observer: O,
+
+    /// Goal used to identify solutions.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub goal: G,
+
+    /// Statistics describing the work performed by the search so far.
+    pub stats: SearchStats,
+
+    /// Maximum number of states to expand over the lifetime of the searcher. By default, no limit is applied.
+    /// Once reached, the search stops with [`SearchLimit::Expansions`]; raise the limit to resume it.
+    pub max_expansions: Option<usize>,
+
+    /// Point in time after which no further states are expanded. By default, no deadline is applied.
+    /// Once passed, the search stops with [`SearchLimit::Deadline`]; move the deadline to resume it.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub deadline: Option<Instant>,
+
+    /// Token through which the search can be stopped from another thread. By default, no token is held.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub cancellation: Option<CancellationToken>,
 }
 
 impl<M> Searcher<M> {
@@ -280,9 +1096,76 @@ impl<M> Searcher<M> {
     where
         M: ExplorationManager,
     {
-        Self {
-            manager: M::initialize(initial_state),
-        }
+        Self::from_manager(M::initialize(vec![initial_state]))
+    }
+
+    /// Create a new search iterator exploring from many initial states at once, such as to find the
+    /// nearest of several sources. Routes yielded by route based managers begin with the initial state
+    /// they were found from.
+    pub fn new_multi(initial_states: impl IntoIterator<Item = M::State>) -> Self
+    where
+        M: ExplorationManager,
+    {
+        Self::from_manager(M::initialize(initial_states.into_iter().collect()))
+    }
+
+    /// Create a new search iterator from an initial state, searching towards a known goal state.
+    pub fn new_with_goal(initial_state: M::State, goal_state: M::State) -> Self
+    where
+        M: GoalExplorationManager,
+    {
+        Self::from_manager(M::initialize_with_goal(initial_state, goal_state))
+    }
+
+    /// Create a new search iterator from an initial state, searching towards a known goal state guided
+    /// by the given heuristic functions in place of [`Scoreable::score`] and
+    /// [`ReverseCostSearchable::reverse_score`]; `heuristic` estimates the cost from a state to the goal
+    /// state, and `reverse_heuristic` the cost from the initial state to a state.
+    pub fn new_with_goal_and_heuristics(
+        initial_state: M::State,
+        goal_state: M::State,
+        heuristic: impl Fn(&M::State) -> M::Score + Send + Sync + 'static,
+        reverse_heuristic: impl Fn(&M::State) -> M::Score + Send + Sync + 'static,
+    ) -> Self
+    where
+        M: GoalHeuristicExplorationManager,
+    {
+        Self::from_manager(M::initialize_with_goal_and_heuristics(
+            initial_state,
+            goal_state,
+            FnHeuristic(Some(Box::new(heuristic))),
+            FnHeuristic(Some(Box::new(reverse_heuristic))),
+        ))
+    }
+
+    /// Create a new search iterator from an initial state, guided by the given heuristic function in
+    /// place of [`Scoreable::score`], for managers whose heuristic is a [`FnHeuristic`]. Use this to
+    /// supply a heuristic that depends on the goal being searched for, or to search states that do not
+    /// implement [`Scoreable`].
+    pub fn new_with_heuristic(
+        initial_state: M::State,
+        heuristic: impl Fn(&M::State) -> M::Score + Send + Sync + 'static,
+    ) -> Self
+    where
+        M: HeuristicExplorationManager,
+    {
+        Self::new_multi_with_heuristic([initial_state], heuristic)
+    }
+
+    /// Create a new search iterator exploring from many initial states at once, guided by the given
+    /// heuristic function; the counterpart of [`Searcher::new_multi`] for managers whose heuristic is a
+    /// [`FnHeuristic`].
+    pub fn new_multi_with_heuristic(
+        initial_states: impl IntoIterator<Item = M::State>,
+        heuristic: impl Fn(&M::State) -> M::Score + Send + Sync + 'static,
+    ) -> Self
+    where
+        M: HeuristicExplorationManager,
+    {
+        Self::from_manager(M::initialize_with_heuristic(
+            initial_states.into_iter().collect(),
+            FnHeuristic(Some(Box::new(heuristic))),
+        ))
     }
 
     /// Create a new search iterator from a default initial state.
@@ -293,37 +1176,337 @@ impl<M> Searcher<M> {
     {
         Self::new(Default::default())
     }
+
+    fn from_manager(manager: M) -> Self
+    where
+        M: ExplorationManager,
+    {
+        let stats = SearchStats {
+            peak_fringe_size: manager.fringe_size(),
+            explored_size: manager.explored_size(),
+            ..Default::default()
+        };
+        Self {
+            manager,
+            observer: NoObserver,
+            goal: SolutionGoal,
+            stats,
+            max_expansions: None,
+            deadline: None,
+            cancellation: None,
+        }
+    }
+}
+
+impl<M, O, G> Searcher<M, O, G> {
+    /// Attach an observer to this searcher, to be notified of every step taken by the search.
+    pub fn with_observer<P>(self, observer: P) -> Searcher<M, P, G> {
+        Searcher {
+            manager: self.manager,
+            observer,
+            goal: self.goal,
+            stats: self.stats,
+            max_expansions: self.max_expansions,
+            deadline: self.deadline,
+            cancellation: self.cancellation,
+        }
+    }
+
+    /// Identify solutions with the given predicate in place of [`SolutionIdentifiable`].
+    ///
+    /// Not available for managers that identify solutions on their own behalf, such as the
+    /// `a_star::parallel` manager, nor for the `bidirectional` managers, whose solutions are the
+    /// routes through the states where the forwards and backwards searches meet:
+    ///
+    /// ```compile_fail
+    /// use space_search::*;
+    ///
+    /// #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+    /// struct Pos(i32, i32);
+    ///
+    /// impl Searchable for Pos {
+    ///     fn next_states(&self) -> impl Iterator<Item = Self> {
+    ///         let &Pos(x, y) = self;
+    ///         [Pos(x + 1, y), Pos(x, y + 1)].into_iter()
+    ///     }
+    /// }
+    ///
+    /// impl ReverseSearchable for Pos {
+    ///     fn previous_states(&self) -> impl Iterator<Item = Self> {
+    ///         let &Pos(x, y) = self;
+    ///         [Pos(x - 1, y), Pos(x, y - 1)].into_iter()
+    ///     }
+    /// }
+    ///
+    /// let searcher: Searcher<search::bidirectional::unguided::Manager<_>> =
+    ///     Searcher::new_with_goal(Pos(0, 0), Pos(5, 5));
+    /// let searcher = searcher.with_goal_predicate(|&Pos(x, _): &Pos| x == 3);
+    /// ```
+    pub fn with_goal_predicate<P>(self, goal: P) -> Searcher<M, O, P>
+    where
+        M: ExplorationManager,
+        P: Fn(&M::State) -> bool + Goal<M::State, M::FringeItem>,
+    {
+        Searcher {
+            manager: self.manager,
+            observer: self.observer,
+            goal,
+            stats: self.stats,
+            max_expansions: self.max_expansions,
+            deadline: self.deadline,
+            cancellation: self.cancellation,
+        }
+    }
+
+    /// Search for the next solution, reporting why the search stopped if none was found.
+    ///
+    /// Unlike [`Iterator::next`], distinguishes a search space that has been exhausted from a search
+    /// that was stopped early by one of the searcher's or manager's limits, or by a failure of the
+    /// manager.
+    pub fn try_next(&mut self) -> SearchOutcome<M::YieldResult>
+    where
+        M: ExplorationManager,
+        O: SearchObserver<M::State>,
+        G: Goal<M::State, M::FringeItem>,
+    {
+        let mut budget = Budget {
+            deadline: self.deadline,
+            cancellation: self.cancellation.clone(),
+            ..Default::default()
+        };
+        loop {
+            if self
+                .cancellation
+                .as_ref()
+                .is_some_and(CancellationToken::is_cancelled)
+            {
+                return SearchOutcome::Cancelled;
+            }
+            if self
+                .max_expansions
+                .is_some_and(|max| self.stats.states_expanded >= max)
+            {
+                return SearchOutcome::LimitReached(SearchLimit::Expansions);
+            }
+            if self
+                .deadline
+                .is_some_and(|deadline| Instant::now() >= deadline)
+            {
+                return SearchOutcome::LimitReached(SearchLimit::Deadline);
+            }
+
+            budget.expansions = self
+                .max_expansions
+                .map(|max| max - self.stats.states_expanded);
+            match self.step_within(&mut budget) {
+                SearchStep::Expanded { .. } => {}
+                SearchStep::Solution(result) => return SearchOutcome::Solution(result),
+                // the manager stopped short of emptying its fringe; report the limit it stopped at
+                SearchStep::FringeEmpty if mem::take(&mut budget.interrupted) => {}
+                SearchStep::FringeEmpty if self.manager.failed() => return SearchOutcome::Failed,
+                SearchStep::FringeEmpty => {
+                    return match self.manager.limit_reached() {
+                        Some(limit) => SearchOutcome::LimitReached(limit),
+                        None => SearchOutcome::Exhausted,
+                    };
+                }
+            }
+        }
+    }
+
+    /// Perform a single step of the search: take one state from the fringe, and either identify it as
+    /// a solution or expand it.
+    ///
+    /// Unlike [`Searcher::try_next`], the searcher's limits and cancellation token are not checked;
+    /// use this to drive a search manually, such as from a debugger or a fixed per-frame budget.
+    pub fn step(&mut self) -> SearchStep<M::YieldResult>
+    where
+        M: ExplorationManager,
+        O: SearchObserver<M::State>,
+        G: Goal<M::State, M::FringeItem>,
+    {
+        self.step_within(&mut Budget::default())
+    }
+
+    fn step_within(&mut self, budget: &mut Budget) -> SearchStep<M::YieldResult>
+    where
+        M: ExplorationManager,
+        O: SearchObserver<M::State>,
+        G: Goal<M::State, M::FringeItem>,
+    {
+        let popped = self.manager.pop_state_within(budget);
+        if budget.expanded > 0 {
+            // states expanded by the manager on its own behalf
+            self.stats.states_expanded += mem::take(&mut budget.expanded);
+            self.stats.states_generated += mem::take(&mut budget.generated);
+            self.stats.peak_fringe_size =
+                self.stats.peak_fringe_size.max(self.manager.fringe_size());
+            self.stats.explored_size = self.manager.explored_size();
+        }
+        let Some(current_state) = popped else {
+            return SearchStep::FringeEmpty;
+        };
+
+        self.observer.state_popped(current_state.as_ref());
+
+        if self.goal.is_goal(&current_state) {
+            self.stats.solutions_yielded += 1;
+            self.observer.solution_found(current_state.as_ref());
+            return SearchStep::Solution(self.manager.prepare_result_from(current_state));
+        }
+
+        let context = self.manager.register_current_state(&current_state);
+        self.stats.states_expanded += 1;
+
+        let mut generated = 0;
+        let mut placed = 0;
+        let mut limited = 0;
+        for item in M::next_states_iter(&current_state) {
+            let new_item = self.manager.prepare_state(&context, item);
+            generated += 1;
+            self.observer.successor_generated(new_item.as_ref());
+            if self.manager.exceeds_limit(&new_item) {
+                limited += 1;
+                self.observer.successor_rejected(new_item.as_ref());
+            } else if self.manager.valid_state(&new_item) {
+                self.manager.place_state(new_item);
+                placed += 1;
+            } else {
+                self.observer.successor_rejected(new_item.as_ref());
+            }
+        }
+        self.observer.state_expanded(current_state.as_ref());
+
+        self.stats.states_generated += generated;
+        self.stats.duplicates_culled += generated - placed - limited;
+        self.stats.limits_culled += limited;
+        self.stats.peak_fringe_size = self.stats.peak_fringe_size.max(self.manager.fringe_size());
+        self.stats.explored_size = self.manager.explored_size();
+
+        SearchStep::Expanded { generated, placed }
+    }
 }
 
-impl<M> Iterator for Searcher<M>
+#[cfg(feature = "serde")]
+impl<M, O, G> Searcher<M, O, G> {
+    /// Save the current state of this search in a compact binary format, to be resumed later with
+    /// [`Searcher::load`]. The observer, goal predicate, deadline and cancellation token of the searcher
+    /// are not saved, and neither are heuristic functions supplied through
+    /// [`Searcher::new_with_heuristic`] and the like; resume such a search with
+    /// [`Searcher::load_with_heuristic`] or [`Searcher::load_with_heuristics`], supplying the same
+    /// functions again.
+    pub fn save(&self, mut writer: impl std::io::Write) -> std::io::Result<()>
+    where
+        M: serde::Serialize,
+    {
+        bincode::serde::encode_into_std_write(self, &mut writer, bincode::config::standard())
+            .map(|_| ())
+            .map_err(std::io::Error::other)
+    }
+
+    /// Resume a search saved with [`Searcher::save`], with a default observer and goal.
+    pub fn load(mut reader: impl std::io::Read) -> std::io::Result<Self>
+    where
+        M: serde::de::DeserializeOwned,
+        O: Default,
+        G: Default,
+    {
+        bincode::serde::decode_from_std_read(&mut reader, bincode::config::standard())
+            .map_err(std::io::Error::other)
+    }
+
+    /// Resume a search saved with [`Searcher::save`] that was created with
+    /// [`Searcher::new_with_heuristic`] or [`Searcher::new_multi_with_heuristic`], guided by the given
+    /// heuristic function. Supply the function the search was created with, as the order of the saved
+    /// fringe was determined by it.
+    pub fn load_with_heuristic(
+        reader: impl std::io::Read,
+        heuristic: impl Fn(&M::State) -> M::Score + Send + Sync + 'static,
+    ) -> std::io::Result<Self>
+    where
+        M: HeuristicExplorationManager + serde::de::DeserializeOwned,
+        O: Default,
+        G: Default,
+    {
+        let mut searcher = Self::load(reader)?;
+        searcher
+            .manager
+            .set_heuristic(FnHeuristic(Some(Box::new(heuristic))));
+        Ok(searcher)
+    }
+
+    /// Resume a search saved with [`Searcher::save`] that was created with
+    /// [`Searcher::new_with_goal_and_heuristics`], guided by the given heuristic functions. Supply the
+    /// functions the search was created with, as the order of the saved fringes was determined by them.
+    pub fn load_with_heuristics(
+        reader: impl std::io::Read,
+        heuristic: impl Fn(&M::State) -> M::Score + Send + Sync + 'static,
+        reverse_heuristic: impl Fn(&M::State) -> M::Score + Send + Sync + 'static,
+    ) -> std::io::Result<Self>
+    where
+        M: GoalHeuristicExplorationManager + serde::de::DeserializeOwned,
+        O: Default,
+        G: Default,
+    {
+        let mut searcher = Self::load(reader)?;
+        searcher.manager.set_heuristics(
+            FnHeuristic(Some(Box::new(heuristic))),
+            FnHeuristic(Some(Box::new(reverse_heuristic))),
+        );
+        Ok(searcher)
+    }
+}
+
+impl<M, O, G> Iterator for Searcher<M, O, G>
 where
     M: ExplorationManager,
-    M::State: SolutionIdentifiable,
+    O: SearchObserver<M::State>,
+    G: Goal<M::State, M::FringeItem>,
 {
     type Item = M::YieldResult;
 
     fn next(&mut self) -> Option<Self::Item> {
-        loop {
-            let current_state = self.manager.pop_state()?;
+        match self.try_next() {
+            SearchOutcome::Solution(result) => Some(result),
+            SearchOutcome::Exhausted
+            | SearchOutcome::LimitReached(_)
+            | SearchOutcome::Cancelled
+            | SearchOutcome::Failed => None,
+        }
+    }
+}
 
-            if current_state.as_ref().is_solution() {
-                return Some(self.manager.prepare_result_from(current_state));
-            }
+/// Internal.
+///
+/// Serializes the contents of a [`Cell`](std::cell::Cell) without requiring them to implement [`Copy`].
+#[cfg(feature = "serde")]
+mod serde_cell {
+    use std::cell::Cell;
 
-            let context = self.manager.register_current_state(&current_state);
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
-            for item in M::next_states_iter(current_state.as_ref()) {
-                let new_item = self.manager.prepare_state(&context, item);
-                if self.manager.valid_state(&new_item) {
-                    self.manager.place_state(new_item);
-                }
-            }
-        }
+    pub fn serialize<T, S>(cell: &Cell<T>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: Serialize + Default,
+        S: Serializer,
+    {
+        let value = cell.take();
+        let result = value.serialize(serializer);
+        cell.set(value);
+        result
+    }
+
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<Cell<T>, D::Error>
+    where
+        T: Deserialize<'de>,
+        D: Deserializer<'de>,
+    {
+        T::deserialize(deserializer).map(Cell::new)
     }
 }
 
-fn prepare_result_from_state_parent_map<S>(
-    parents: &[StateParent<S>],
+fn prepare_result_from_state_parent_map<S, P>(
+    parents: &[P],
     StateParent {
         mut state,
         parent: mut maybe_parent_index,
@@ -331,20 +1514,144 @@ fn prepare_result_from_state_parent_map<S>(
 ) -> Vec<S>
 where
     S: Clone,
+    P: Clone + Into<StateParent<S>>,
 {
     let mut result = VecDeque::new();
     while let Some(parent_index) = maybe_parent_index {
-        result.push_front(state);
+        result.push_front(Arc::unwrap_or_clone(state));
         let StateParent {
             state: new_state,
             parent: new_parent_index,
         } = parents
             .get(parent_index)
             .expect("Parent state will always exist if parent index exists")
-            .clone();
+            .clone()
+            .into();
         state = new_state;
         maybe_parent_index = new_parent_index;
     }
-    result.push_front(state);
+    result.push_front(Arc::unwrap_or_clone(state));
     result.into()
 }
+
+fn prepare_cost_route_from_state_parent_map<S, C>(
+    parents: &[StateParentCumulativeCost<S, C>],
+    StateParentCumulativeCost {
+        mut state,
+        mut parent,
+        cumulative_cost: total_cost,
+    }: StateParentCumulativeCost<S, C>,
+) -> CostRoute<S, C>
+where
+    S: Clone,
+    C: Sub<C, Output = C> + Clone,
+{
+    let mut states = VecDeque::new();
+    let mut step_costs = VecDeque::new();
+    let mut cumulative_cost = total_cost.clone();
+    while let Some(parent_index) = parent {
+        let parent_item = parents
+            .get(parent_index)
+            .expect("Parent state will always exist if parent index exists");
+        states.push_front(Arc::unwrap_or_clone(state));
+        step_costs.push_front(cumulative_cost - parent_item.cumulative_cost.clone());
+        state = parent_item.state.clone();
+        parent = parent_item.parent;
+        cumulative_cost = parent_item.cumulative_cost.clone();
+    }
+    states.push_front(Arc::unwrap_or_clone(state));
+    CostRoute {
+        states: states.into(),
+        step_costs: step_costs.into(),
+        total_cost,
+    }
+}
+
+fn prepare_action_route_from_state_parent_map<S, A>(
+    parents: &[StateParentAction<S, A>],
+    StateParentAction {
+        mut state,
+        mut parent,
+        mut action,
+    }: StateParentAction<S, A>,
+) -> ActionRoute<S, A>
+where
+    S: Clone,
+    A: Clone,
+{
+    let mut states = VecDeque::new();
+    let mut actions = VecDeque::new();
+    while let Some(parent_index) = parent {
+        let parent_item = parents
+            .get(parent_index)
+            .expect("Parent state will always exist if parent index exists");
+        states.push_front(Arc::unwrap_or_clone(state));
+        actions.push_front(action.expect("Only the initial state is reached without an action"));
+        state = parent_item.state.clone();
+        parent = parent_item.parent;
+        action = parent_item.action.clone();
+    }
+    states.push_front(Arc::unwrap_or_clone(state));
+    ActionRoute {
+        states: states.into(),
+        actions: actions.into(),
+    }
+}
+
+fn prepare_cost_route_from_bidirectional_state_parent_maps<S, C>(
+    forward_parents: &[StateParentCumulativeCost<S, C>],
+    backward_parents: &[StateParentCumulativeCost<S, C>],
+    meeting_item: StateParentCumulativeCost<S, C>,
+    mut backward_parent: Option<usize>,
+    backward_cost: C,
+) -> CostRoute<S, C>
+where
+    S: Clone,
+    C: Add<C, Output = C> + Sub<C, Output = C> + Clone,
+{
+    let mut route = prepare_cost_route_from_state_parent_map(forward_parents, meeting_item);
+    route.total_cost = route.total_cost + backward_cost.clone();
+    let mut cumulative_cost = backward_cost;
+    while let Some(parent_index) = backward_parent {
+        let parent_item = backward_parents
+            .get(parent_index)
+            .expect("Parent state will always exist if parent index exists");
+        route.states.push(S::clone(&parent_item.state));
+        route
+            .step_costs
+            .push(cumulative_cost - parent_item.cumulative_cost.clone());
+        backward_parent = parent_item.parent;
+        cumulative_cost = parent_item.cumulative_cost.clone();
+    }
+    route
+}
+
+fn prepare_result_from_bidirectional_state_parent_maps<S, P>(
+    forward_parents: &[P],
+    backward_parents: &[P],
+    meeting_state: Arc<S>,
+    forward_parent: Option<usize>,
+    mut backward_parent: Option<usize>,
+) -> Vec<S>
+where
+    S: Clone,
+    P: Clone + Into<StateParent<S>>,
+{
+    let mut result = prepare_result_from_state_parent_map(
+        forward_parents,
+        StateParent {
+            state: meeting_state,
+            parent: forward_parent,
+        },
+    );
+    while let Some(parent_index) = backward_parent {
+        let StateParent { state, parent } = backward_parents
+            .get(parent_index)
+            .expect("Parent state will always exist if parent index exists")
+            .clone()
+            .into();
+        result.push(Arc::unwrap_or_clone(state));
+        backward_parent = parent;
+    }
+    result
+}
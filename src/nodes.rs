@@ -0,0 +1,184 @@
+//! Internal storage of the explored states of route yielding managers.
+
+use std::{
+    hash::{BuildHasher, Hash, RandomState},
+    marker::PhantomData,
+    ops::Deref,
+};
+
+use hashbrown::{hash_table::Entry, HashTable};
+
+/// Internal.
+///
+/// Table of explored states, each stored once in a node along with the index of its parent node,
+/// serving as both the explored set and the parent map of route yielding managers. Nodes are never
+/// modified or removed once stored, so the routes through them stay intact; storing a node for a state
+/// already stored makes it the node found for that state from then on.
+pub(crate) struct Nodes<S, N> {
+    nodes: Vec<N>,
+    indices: HashTable<usize>,
+    hasher: RandomState,
+    states: PhantomData<S>,
+}
+
+impl<S, N> Nodes<S, N>
+where
+    S: Eq + Hash,
+    N: AsRef<S>,
+{
+    pub(crate) fn new() -> Self {
+        Self {
+            nodes: Vec::new(),
+            indices: HashTable::new(),
+            hasher: RandomState::new(),
+            states: PhantomData,
+        }
+    }
+
+    /// Return the index of the latest node stored for the state, if any.
+    pub(crate) fn index_of(&self, state: &S) -> Option<usize> {
+        self.indices
+            .find(self.hasher.hash_one(state), |&index| {
+                self.nodes[index].as_ref() == state
+            })
+            .copied()
+    }
+
+    pub(crate) fn contains(&self, state: &S) -> bool {
+        self.index_of(state).is_some()
+    }
+
+    /// Store the node if its state is not stored yet, returning its index.
+    pub(crate) fn insert(&mut self, node: N) -> Option<usize> {
+        let Self {
+            nodes,
+            indices,
+            hasher,
+            ..
+        } = self;
+        let state = node.as_ref();
+        match indices.entry(
+            hasher.hash_one(state),
+            |&index| nodes[index].as_ref() == state,
+            |&index| hasher.hash_one(nodes[index].as_ref()),
+        ) {
+            Entry::Occupied(_) => None,
+            Entry::Vacant(entry) => {
+                entry.insert(nodes.len());
+                nodes.push(node);
+                Some(nodes.len() - 1)
+            }
+        }
+    }
+
+    /// Store the node, superseding any node previously stored for its state, returning its index.
+    pub(crate) fn push(&mut self, node: N) -> usize {
+        let Self {
+            nodes,
+            indices,
+            hasher,
+            ..
+        } = self;
+        let state = node.as_ref();
+        let index = nodes.len();
+        match indices.entry(
+            hasher.hash_one(state),
+            |&index| nodes[index].as_ref() == state,
+            |&index| hasher.hash_one(nodes[index].as_ref()),
+        ) {
+            Entry::Occupied(mut entry) => *entry.get_mut() = index,
+            Entry::Vacant(entry) => {
+                entry.insert(index);
+            }
+        }
+        nodes.push(node);
+        index
+    }
+
+    /// Return the number of distinct states stored.
+    pub(crate) fn states_len(&self) -> usize {
+        self.indices.len()
+    }
+}
+
+impl<S, N> Deref for Nodes<S, N> {
+    type Target = [N];
+
+    fn deref(&self) -> &Self::Target {
+        &self.nodes
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<S, N> serde::Serialize for Nodes<S, N>
+where
+    N: serde::Serialize,
+{
+    fn serialize<T>(&self, serializer: T) -> Result<T::Ok, T::Error>
+    where
+        T: serde::Serializer,
+    {
+        self.nodes.serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, S, N> serde::Deserialize<'de> for Nodes<S, N>
+where
+    S: Eq + Hash,
+    N: serde::Deserialize<'de> + AsRef<S>,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let mut nodes = Self::new();
+        for node in Vec::<N>::deserialize(deserializer)? {
+            nodes.push(node);
+        }
+        Ok(nodes)
+    }
+}
+
+#[test]
+fn test() {
+    use crate::StateParent;
+    use std::sync::Arc;
+
+    let mut nodes = Nodes::new();
+    assert_eq!(
+        nodes.insert(StateParent {
+            state: Arc::new('a'),
+            parent: None,
+        }),
+        Some(0)
+    );
+    assert_eq!(
+        nodes.insert(StateParent {
+            state: Arc::new('b'),
+            parent: Some(0),
+        }),
+        Some(1)
+    );
+    assert_eq!(
+        nodes.insert(StateParent {
+            state: Arc::new('b'),
+            parent: None,
+        }),
+        None
+    );
+    assert_eq!(
+        nodes.push(StateParent {
+            state: Arc::new('b'),
+            parent: None,
+        }),
+        2
+    );
+
+    assert_eq!(nodes.index_of(&'a'), Some(0));
+    assert_eq!(nodes.index_of(&'b'), Some(2));
+    assert!(!nodes.contains(&'c'));
+    assert_eq!(nodes[1].parent, Some(0));
+    assert_eq!(nodes.len(), 3);
+    assert_eq!(nodes.states_len(), 2);
+}
@@ -1,5 +1,8 @@
 //! Module containing all available search managers, organized into a feature-based hierarchy.
 
+pub mod a_star;
+pub mod bidirectional;
 pub mod guided;
+pub mod ida_star;
 pub mod unguided;
-pub mod a_star;
\ No newline at end of file
+pub mod uniform_cost;
@@ -1,4 +1,8 @@
 //! Module containing all A* based search managers.
 
+pub mod anytime;
+pub mod focal;
+pub mod no_route;
+#[cfg(feature = "parallel")]
+pub mod parallel;
 pub mod route;
-pub mod no_route;
\ No newline at end of file
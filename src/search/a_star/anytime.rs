@@ -0,0 +1,9 @@
+//! Module containing all Anytime Repairing A* (ARA*) based search managers.
+//!
+//! These managers first search with a high heuristic weight to find a solution quickly, then repeatedly
+//! lower the weight and repair the previous search to find cheaper solutions, reusing the cost of every
+//! state reached so far. Each solution yielded costs strictly less than the previous one; once the
+//! weight reaches one and no cheaper solution remains, the managers stop yielding.
+
+pub mod no_route;
+pub mod route;
@@ -0,0 +1,352 @@
+use std::{
+    cell::Cell,
+    collections::{HashMap, HashSet},
+    hash::Hash,
+    ops::{Add, Mul, Sub},
+};
+
+use num::{One, Zero};
+
+use crate::{
+    ExplorationManager, FnHeuristic, Heuristic, HeuristicExplorationManager, OrderedSearchable,
+    ScoreableHeuristic, ScoredFringe, StateCumulativeCost, UniformCostSearchable,
+};
+
+/// Anytime Repairing A* based, solution-only yielding, prior state exploration culling search manager.
+///
+/// Requires an admissible heuristic that never scores a state below zero.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(
+        serialize = "S: serde::Serialize, S::Cost: serde::Serialize",
+        deserialize = "S: serde::Deserialize<'de> + Eq + Hash, S::Cost: serde::Deserialize<'de>"
+    ))
+)]
+pub struct Manager<S, H = ScoreableHeuristic>
+where
+    S: UniformCostSearchable,
+    H: Heuristic<S>,
+{
+    best_costs: HashMap<S, S::Cost>,
+    closed: HashSet<S>,
+    fringe: ScoredFringe<StateCumulativeCost<S, S::Cost>, S::Cost>,
+    inconsistent: Vec<StateCumulativeCost<S, S::Cost>>,
+    incumbent_cost: Option<S::Cost>,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_cell"))]
+    solution_cost: Cell<Option<S::Cost>>,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    heuristic: H,
+
+    /// Weight applied to the heuristic score of every state in the current search iteration.
+    /// Defaults to two; set this before searching to choose the weight of the first iteration.
+    /// Being a cost, the weight of integer costs is a whole number; use a fractional cost type such as
+    /// [`num::rational::Ratio`] for weights like 1.5.
+    pub weight: S::Cost,
+
+    /// Amount the weight is lowered by after every solution found, down to a minimum of one.
+    /// Defaults to one.
+    pub weight_step: S::Cost,
+}
+
+impl<S, H> Manager<S, H>
+where
+    S: UniformCostSearchable + Clone + Eq + Hash,
+    S::Cost: Add<S::Cost, Output = S::Cost>
+        + Sub<S::Cost, Output = S::Cost>
+        + Mul<S::Cost, Output = S::Cost>
+        + Zero
+        + One
+        + Clone
+        + From<H::Score>,
+    H: Heuristic<S>,
+{
+    fn new(initial_states: Vec<S>, heuristic: H) -> Self {
+        let mut manager = Self {
+            best_costs: HashMap::new(),
+            closed: HashSet::new(),
+            fringe: ScoredFringe::new(),
+            inconsistent: Vec::new(),
+            incumbent_cost: None,
+            solution_cost: Cell::new(None),
+            heuristic,
+            weight: S::Cost::one() + S::Cost::one(),
+            weight_step: S::Cost::one(),
+        };
+        for state in initial_states {
+            if manager
+                .best_costs
+                .insert(state.clone(), S::Cost::zero())
+                .is_none()
+            {
+                manager.push(StateCumulativeCost {
+                    state,
+                    cumulative_cost: S::Cost::zero(),
+                });
+            }
+        }
+        manager
+    }
+
+    fn push(&mut self, item: StateCumulativeCost<S, S::Cost>) {
+        let score = S::Cost::from(self.heuristic.score(&item.state)) * self.weight.clone()
+            + item.cumulative_cost.clone();
+        self.fringe.push(OrderedSearchable { state: item, score })
+    }
+
+    /// Return `true` if the item can no longer lead to a solution cheaper than the best one found so far.
+    fn pruned(&self, item: &StateCumulativeCost<S, S::Cost>) -> bool {
+        self.incumbent_cost.as_ref().is_some_and(|incumbent| {
+            S::Cost::from(self.heuristic.score(&item.state)) + item.cumulative_cost.clone()
+                >= *incumbent
+        })
+    }
+
+    /// Return `true` if the item has been superseded by a cheaper path to the same state.
+    fn stale(&self, item: &StateCumulativeCost<S, S::Cost>) -> bool {
+        self.best_costs
+            .get(&item.state)
+            .is_some_and(|best| item.cumulative_cost > *best)
+    }
+
+    /// Lower the weight and reinsert every open and inconsistent state for the next search iteration.
+    fn begin_iteration(&mut self) {
+        // compare before subtracting, as unsigned costs cannot go below zero
+        self.weight = if self.weight > self.weight_step.clone() + S::Cost::one() {
+            self.weight.clone() - self.weight_step.clone()
+        } else {
+            S::Cost::one()
+        };
+        self.closed.clear();
+        let items: Vec<_> = self
+            .fringe
+            .drain()
+            .map(|o| o.state)
+            .chain(self.inconsistent.drain(..))
+            .collect();
+        for item in items {
+            if !self.stale(&item) && !self.pruned(&item) {
+                self.push(item);
+            }
+        }
+    }
+}
+
+impl<S, H> ExplorationManager for Manager<S, H>
+where
+    S: UniformCostSearchable + Clone + Eq + Hash,
+    S::Cost: Add<S::Cost, Output = S::Cost>
+        + Sub<S::Cost, Output = S::Cost>
+        + Mul<S::Cost, Output = S::Cost>
+        + Zero
+        + One
+        + Clone
+        + From<H::Score>,
+    H: Heuristic<S>,
+{
+    type State = S;
+    type YieldResult = S;
+
+    type FringeItem = StateCumulativeCost<S, S::Cost>;
+
+    type CurrentStateContext = S::Cost;
+
+    type NextStatesIterItem = (S, S::Cost);
+
+    fn initialize(initial_states: Vec<S>) -> Self {
+        Self::new(initial_states, H::default())
+    }
+
+    fn pop_state(&mut self) -> Option<Self::FringeItem> {
+        if let Some(solution_cost) = self.solution_cost.take() {
+            self.incumbent_cost = Some(solution_cost);
+            self.begin_iteration();
+        }
+        loop {
+            let Some(OrderedSearchable { state: item, .. }) = self.fringe.pop() else {
+                // the current iteration ran out of states without finding a cheaper solution;
+                // the search is only over once the weight one iteration has been exhausted
+                if self.weight > S::Cost::one() || !self.inconsistent.is_empty() {
+                    self.begin_iteration();
+                    continue;
+                }
+                return None;
+            };
+            if self.stale(&item) || self.pruned(&item) {
+                continue;
+            }
+            return Some(item);
+        }
+    }
+
+    fn prepare_result_from(&self, item: Self::FringeItem) -> Self::YieldResult {
+        self.solution_cost.set(Some(item.cumulative_cost.clone()));
+        item.state
+    }
+
+    fn valid_state(&mut self, item: &Self::FringeItem) -> bool {
+        if self
+            .best_costs
+            .get(&item.state)
+            .is_some_and(|best| item.cumulative_cost >= *best)
+        {
+            return false;
+        }
+        self.best_costs
+            .insert(item.state.clone(), item.cumulative_cost.clone());
+        if self.pruned(item) {
+            return false;
+        }
+        if self.closed.contains(&item.state) {
+            // already expanded during this iteration; revisit it in the next one
+            self.inconsistent.push(item.clone());
+            return false;
+        }
+        true
+    }
+
+    fn place_state(&mut self, item: Self::FringeItem) {
+        self.push(item);
+    }
+
+    fn register_current_state(&mut self, item: &Self::FringeItem) -> Self::CurrentStateContext {
+        self.closed.insert(item.state.clone());
+        item.cumulative_cost.clone()
+    }
+
+    fn prepare_state(
+        &self,
+        cumulative_cost: &Self::CurrentStateContext,
+        (state, traversal_cost): Self::NextStatesIterItem,
+    ) -> Self::FringeItem {
+        StateCumulativeCost {
+            state,
+            cumulative_cost: cumulative_cost.clone() + traversal_cost,
+        }
+    }
+
+    fn next_states_iter(
+        current_state: &Self::FringeItem,
+    ) -> impl Iterator<Item = Self::NextStatesIterItem> {
+        current_state.as_ref().next_states_with_costs()
+    }
+
+    fn fringe_size(&self) -> usize {
+        self.fringe.len() + self.inconsistent.len()
+    }
+
+    fn explored_size(&self) -> usize {
+        self.best_costs.len()
+    }
+}
+
+impl<S, C> HeuristicExplorationManager for Manager<S, FnHeuristic<S, C>>
+where
+    S: UniformCostSearchable + Clone + Eq + Hash,
+    S::Cost: Add<S::Cost, Output = S::Cost>
+        + Sub<S::Cost, Output = S::Cost>
+        + Mul<S::Cost, Output = S::Cost>
+        + Zero
+        + One
+        + Clone
+        + From<C>,
+    C: Ord + Default,
+{
+    type Score = C;
+
+    fn initialize_with_heuristic(initial_states: Vec<S>, heuristic: FnHeuristic<S, C>) -> Self {
+        Self::new(initial_states, heuristic)
+    }
+
+    fn set_heuristic(&mut self, heuristic: FnHeuristic<S, C>) {
+        self.heuristic = heuristic;
+    }
+}
+
+#[test]
+fn test() {
+    use crate::*;
+
+    #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+    struct Pos(i32, i32);
+
+    impl CostSearchable for Pos {
+        type Cost = i32;
+
+        fn next_states_with_costs(&self) -> impl Iterator<Item = (Self, Self::Cost)> {
+            let &Pos(x, y) = self;
+            [
+                (Pos(x + 1, y), 1),
+                (Pos(x, y + 1), 1),
+                (Pos(x + 1, y + 1), 4),
+            ]
+            .into_iter()
+        }
+    }
+
+    impl SolutionIdentifiable for Pos {
+        fn is_solution(&self) -> bool {
+            let &Pos(x, y) = self;
+            x == 3 && y == 3
+        }
+    }
+
+    impl Scoreable for Pos {
+        type Score = i32;
+
+        fn score(&self) -> Self::Score {
+            let &Pos(x, y) = self;
+            (x - 3).abs() + (y - 3).abs()
+        }
+    }
+
+    let mut searcher: Searcher<Manager<_>> = Searcher::new(Pos(0, 0));
+    searcher.manager.weight = 4;
+    assert_eq!(searcher.next(), Some(Pos(3, 3)));
+}
+
+#[test]
+fn test_exhausted_iteration() {
+    use crate::*;
+
+    #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+    struct Node(usize);
+
+    impl CostSearchable for Node {
+        type Cost = i32;
+
+        fn next_states_with_costs(&self) -> impl Iterator<Item = (Self, Self::Cost)> {
+            let edges: &[(usize, i32)] = match self.0 {
+                0 => &[(4, 7), (2, 1)],
+                2 => &[(3, 8), (4, 6)],
+                3 => &[(4, 9), (6, 2), (2, 5), (0, 5)],
+                4 => &[(3, 5), (6, 7)],
+                5 => &[(1, 1)],
+                6 => &[(3, 7), (3, 4), (3, 9), (5, 8)],
+                _ => &[],
+            };
+            edges.iter().map(|&(to, cost)| (Node(to), cost))
+        }
+    }
+
+    impl SolutionIdentifiable for Node {
+        fn is_solution(&self) -> bool {
+            self.0 == 6
+        }
+    }
+
+    impl Scoreable for Node {
+        type Score = i32;
+
+        fn score(&self) -> Self::Score {
+            [5, 0, 5, 1, 3, 0, 0][self.0]
+        }
+    }
+
+    // the first solution costs 14 and the optimal one 11, found only after the weight four
+    // iteration runs out of states
+    let mut searcher: Searcher<Manager<_>> = Searcher::new(Node(0));
+    searcher.manager.weight = 5;
+    assert_eq!(searcher.by_ref().count(), 2);
+    assert_eq!(searcher.manager.weight, 1);
+}
@@ -0,0 +1,494 @@
+use std::{
+    cell::Cell,
+    collections::HashSet,
+    hash::Hash,
+    ops::{Add, Mul, Sub},
+    sync::Arc,
+};
+
+use num::{One, Zero};
+
+use crate::{
+    prepare_cost_route_from_state_parent_map, CostRoute, ExplorationManager, FnHeuristic,
+    Heuristic, HeuristicExplorationManager, NodeItem, Nodes, OrderedSearchable, ScoreableHeuristic,
+    ScoredFringe, StateParentCumulativeCost, UniformCostSearchable,
+};
+
+/// Anytime Repairing A* based, solution-route yielding, prior state exploration culling search manager.
+///
+/// Requires an admissible heuristic that never scores a state below zero.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(
+        serialize = "S: serde::Serialize, S::Cost: serde::Serialize",
+        deserialize = "S: serde::Deserialize<'de> + Eq + Hash, S::Cost: serde::Deserialize<'de>"
+    ))
+)]
+pub struct Manager<S, H = ScoreableHeuristic>
+where
+    S: UniformCostSearchable,
+    H: Heuristic<S>,
+{
+    explored: Nodes<S, StateParentCumulativeCost<S, S::Cost>>,
+    closed: HashSet<usize>,
+    fringe: ScoredFringe<usize, S::Cost>,
+    inconsistent: Vec<usize>,
+    incumbent_cost: Option<S::Cost>,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_cell"))]
+    solution_cost: Cell<Option<S::Cost>>,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    heuristic: H,
+
+    /// Weight applied to the heuristic score of every state in the current search iteration.
+    /// Defaults to two; set this before searching to choose the weight of the first iteration.
+    /// Being a cost, the weight of integer costs is a whole number; use a fractional cost type such as
+    /// [`num::rational::Ratio`] for weights like 1.5.
+    pub weight: S::Cost,
+
+    /// Amount the weight is lowered by after every solution found, down to a minimum of one.
+    /// Defaults to one.
+    pub weight_step: S::Cost,
+}
+
+impl<S, H> Manager<S, H>
+where
+    S: UniformCostSearchable + Clone + Eq + Hash,
+    S::Cost: Add<S::Cost, Output = S::Cost>
+        + Sub<S::Cost, Output = S::Cost>
+        + Mul<S::Cost, Output = S::Cost>
+        + Zero
+        + One
+        + Clone
+        + From<H::Score>,
+    H: Heuristic<S>,
+{
+    fn new(initial_states: Vec<S>, heuristic: H) -> Self {
+        let mut manager = Self {
+            explored: Nodes::new(),
+            closed: HashSet::new(),
+            fringe: ScoredFringe::new(),
+            inconsistent: Vec::new(),
+            incumbent_cost: None,
+            solution_cost: Cell::new(None),
+            heuristic,
+            weight: S::Cost::one() + S::Cost::one(),
+            weight_step: S::Cost::one(),
+        };
+        for state in initial_states {
+            if let Some(index) = manager.explored.insert(StateParentCumulativeCost {
+                state: Arc::new(state),
+                parent: None,
+                cumulative_cost: S::Cost::zero(),
+            }) {
+                manager.push(index);
+            }
+        }
+        manager
+    }
+
+    fn push(&mut self, index: usize) {
+        let item = &self.explored[index];
+        let score = S::Cost::from(self.heuristic.score(&item.state)) * self.weight.clone()
+            + item.cumulative_cost.clone();
+        self.fringe.push(OrderedSearchable {
+            state: index,
+            score,
+        })
+    }
+
+    /// Return `true` if the item can no longer lead to a solution cheaper than the best one found so far.
+    fn pruned(&self, item: &StateParentCumulativeCost<S, S::Cost>) -> bool {
+        self.incumbent_cost.as_ref().is_some_and(|incumbent| {
+            S::Cost::from(self.heuristic.score(&item.state)) + item.cumulative_cost.clone()
+                >= *incumbent
+        })
+    }
+
+    /// Return `true` if the explored state has been superseded by a cheaper path to the same state.
+    fn stale(&self, index: usize) -> bool {
+        self.explored.index_of(&self.explored[index].state) != Some(index)
+    }
+
+    /// Lower the weight and reinsert every open and inconsistent state for the next search iteration.
+    fn begin_iteration(&mut self) {
+        // compare before subtracting, as unsigned costs cannot go below zero
+        self.weight = if self.weight > self.weight_step.clone() + S::Cost::one() {
+            self.weight.clone() - self.weight_step.clone()
+        } else {
+            S::Cost::one()
+        };
+        self.closed.clear();
+        let indices: Vec<_> = self
+            .fringe
+            .drain()
+            .map(|o| o.state)
+            .chain(self.inconsistent.drain(..))
+            .collect();
+        for index in indices {
+            if !self.stale(index) && !self.pruned(&self.explored[index]) {
+                self.push(index);
+            }
+        }
+    }
+}
+
+impl<S, H> ExplorationManager for Manager<S, H>
+where
+    S: UniformCostSearchable + Clone + Eq + Hash,
+    S::Cost: Add<S::Cost, Output = S::Cost>
+        + Sub<S::Cost, Output = S::Cost>
+        + Mul<S::Cost, Output = S::Cost>
+        + Zero
+        + One
+        + Clone
+        + From<H::Score>,
+    H: Heuristic<S>,
+{
+    type State = S;
+    type YieldResult = CostRoute<S, S::Cost>;
+
+    type FringeItem = NodeItem<StateParentCumulativeCost<S, S::Cost>>;
+
+    type CurrentStateContext = (usize, S::Cost);
+
+    type NextStatesIterItem = (S, S::Cost);
+
+    fn initialize(initial_states: Vec<S>) -> Self {
+        Self::new(initial_states, H::default())
+    }
+
+    fn pop_state(&mut self) -> Option<Self::FringeItem> {
+        if let Some(solution_cost) = self.solution_cost.take() {
+            self.incumbent_cost = Some(solution_cost);
+            self.begin_iteration();
+        }
+        loop {
+            let Some(OrderedSearchable { state: index, .. }) = self.fringe.pop() else {
+                // the current iteration ran out of states without finding a cheaper solution;
+                // the search is only over once the weight one iteration has been exhausted
+                if self.weight > S::Cost::one() || !self.inconsistent.is_empty() {
+                    self.begin_iteration();
+                    continue;
+                }
+                return None;
+            };
+            if self.stale(index) || self.pruned(&self.explored[index]) {
+                continue;
+            }
+            return Some(NodeItem {
+                item: self.explored[index].clone(),
+                node: Some(index),
+            });
+        }
+    }
+
+    fn prepare_result_from(&self, NodeItem { item, .. }: Self::FringeItem) -> Self::YieldResult {
+        self.solution_cost.set(Some(item.cumulative_cost.clone()));
+        prepare_cost_route_from_state_parent_map(&self.explored, item)
+    }
+
+    fn valid_state(&mut self, NodeItem { item, .. }: &Self::FringeItem) -> bool {
+        let best = self.explored.index_of(&item.state);
+        if best.is_some_and(|best| item.cumulative_cost >= self.explored[best].cumulative_cost)
+            || self.pruned(item)
+        {
+            return false;
+        }
+        if best.is_some_and(|best| self.closed.contains(&best)) {
+            // already expanded during this iteration; revisit it in the next one, and keep it
+            // closed until then
+            let index = self.explored.push(item.clone());
+            self.closed.insert(index);
+            self.inconsistent.push(index);
+            return false;
+        }
+        true
+    }
+
+    fn place_state(&mut self, NodeItem { item, .. }: Self::FringeItem) {
+        let index = self.explored.push(item);
+        self.push(index);
+    }
+
+    fn register_current_state(
+        &mut self,
+        NodeItem { item, node }: &Self::FringeItem,
+    ) -> Self::CurrentStateContext {
+        let index = node.expect("Popped items always carry their node");
+        self.closed.insert(index);
+        (index, item.cumulative_cost.clone())
+    }
+
+    fn prepare_state(
+        &self,
+        (parent, cumulative_cost): &Self::CurrentStateContext,
+        (state, traversal_cost): Self::NextStatesIterItem,
+    ) -> Self::FringeItem {
+        NodeItem {
+            item: StateParentCumulativeCost {
+                state: Arc::new(state),
+                parent: Some(*parent),
+                cumulative_cost: cumulative_cost.clone() + traversal_cost,
+            },
+            node: None,
+        }
+    }
+
+    fn next_states_iter(
+        current_state: &Self::FringeItem,
+    ) -> impl Iterator<Item = Self::NextStatesIterItem> {
+        current_state.as_ref().next_states_with_costs()
+    }
+
+    fn fringe_size(&self) -> usize {
+        self.fringe.len() + self.inconsistent.len()
+    }
+
+    fn explored_size(&self) -> usize {
+        self.explored.states_len()
+    }
+}
+
+impl<S, C> HeuristicExplorationManager for Manager<S, FnHeuristic<S, C>>
+where
+    S: UniformCostSearchable + Clone + Eq + Hash,
+    S::Cost: Add<S::Cost, Output = S::Cost>
+        + Sub<S::Cost, Output = S::Cost>
+        + Mul<S::Cost, Output = S::Cost>
+        + Zero
+        + One
+        + Clone
+        + From<C>,
+    C: Ord + Default,
+{
+    type Score = C;
+
+    fn initialize_with_heuristic(initial_states: Vec<S>, heuristic: FnHeuristic<S, C>) -> Self {
+        Self::new(initial_states, heuristic)
+    }
+
+    fn set_heuristic(&mut self, heuristic: FnHeuristic<S, C>) {
+        self.heuristic = heuristic;
+    }
+}
+
+#[test]
+fn test() {
+    use crate::*;
+
+    #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+    struct Pos(i32, i32);
+
+    impl CostSearchable for Pos {
+        type Cost = i32;
+
+        fn next_states_with_costs(&self) -> impl Iterator<Item = (Self, Self::Cost)> {
+            let &Pos(x, y) = self;
+            [
+                (Pos(x + 1, y), 1),
+                (Pos(x, y + 1), 1),
+                (Pos(x + 1, y + 1), 4),
+            ]
+            .into_iter()
+        }
+    }
+
+    impl SolutionIdentifiable for Pos {
+        fn is_solution(&self) -> bool {
+            let &Pos(x, y) = self;
+            x == 3 && y == 3
+        }
+    }
+
+    impl Scoreable for Pos {
+        type Score = i32;
+
+        fn score(&self) -> Self::Score {
+            let &Pos(x, y) = self;
+            (x - 3).abs() + (y - 3).abs()
+        }
+    }
+
+    let mut searcher: Searcher<Manager<_>> = Searcher::new(Pos(0, 0));
+    searcher.manager.weight = 4;
+    assert_eq!(
+        searcher
+            .map(|route| (route.states, route.total_cost))
+            .collect::<Vec<_>>(),
+        vec![
+            (vec![Pos(0, 0), Pos(1, 1), Pos(2, 2), Pos(3, 3)], 12),
+            (
+                vec![Pos(0, 0), Pos(1, 1), Pos(2, 2), Pos(3, 2), Pos(3, 3)],
+                10
+            ),
+            (
+                vec![
+                    Pos(0, 0),
+                    Pos(1, 1),
+                    Pos(2, 1),
+                    Pos(3, 1),
+                    Pos(3, 2),
+                    Pos(3, 3)
+                ],
+                8
+            ),
+            (
+                vec![
+                    Pos(0, 0),
+                    Pos(0, 1),
+                    Pos(1, 1),
+                    Pos(2, 1),
+                    Pos(2, 2),
+                    Pos(2, 3),
+                    Pos(3, 3)
+                ],
+                6
+            )
+        ]
+    );
+}
+
+#[test]
+fn test_unsigned_weight_step() {
+    use crate::*;
+
+    #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+    struct Pos(u32, u32);
+
+    impl CostSearchable for Pos {
+        type Cost = u32;
+
+        fn next_states_with_costs(&self) -> impl Iterator<Item = (Self, Self::Cost)> {
+            let &Pos(x, y) = self;
+            [
+                (Pos(x + 1, y), 1),
+                (Pos(x, y + 1), 1),
+                (Pos(x + 1, y + 1), 4),
+            ]
+            .into_iter()
+        }
+    }
+
+    impl SolutionIdentifiable for Pos {
+        fn is_solution(&self) -> bool {
+            let &Pos(x, y) = self;
+            x == 3 && y == 3
+        }
+    }
+
+    impl Scoreable for Pos {
+        type Score = u32;
+
+        fn score(&self) -> Self::Score {
+            let &Pos(x, y) = self;
+            x.abs_diff(3) + y.abs_diff(3)
+        }
+    }
+
+    let mut searcher: Searcher<Manager<_>> = Searcher::new(Pos(0, 0));
+    searcher.manager.weight = 2;
+    searcher.manager.weight_step = 5;
+    let costs: Vec<_> = searcher.by_ref().map(|route| route.total_cost).collect();
+    assert_eq!(costs.last(), Some(&6));
+    assert_eq!(searcher.manager.weight, 1);
+}
+
+#[test]
+fn test_exhausted_iteration() {
+    use crate::*;
+
+    #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+    struct Node(usize);
+
+    impl CostSearchable for Node {
+        type Cost = i32;
+
+        fn next_states_with_costs(&self) -> impl Iterator<Item = (Self, Self::Cost)> {
+            let edges: &[(usize, i32)] = match self.0 {
+                0 => &[(4, 7), (2, 1)],
+                2 => &[(3, 8), (4, 6)],
+                3 => &[(4, 9), (6, 2), (2, 5), (0, 5)],
+                4 => &[(3, 5), (6, 7)],
+                5 => &[(1, 1)],
+                6 => &[(3, 7), (3, 4), (3, 9), (5, 8)],
+                _ => &[],
+            };
+            edges.iter().map(|&(to, cost)| (Node(to), cost))
+        }
+    }
+
+    impl SolutionIdentifiable for Node {
+        fn is_solution(&self) -> bool {
+            self.0 == 6
+        }
+    }
+
+    impl Scoreable for Node {
+        type Score = i32;
+
+        fn score(&self) -> Self::Score {
+            [5, 0, 5, 1, 3, 0, 0][self.0]
+        }
+    }
+
+    // the weight four iteration runs out of states without improving on the first solution, and
+    // the search must carry on with lower weights to find the optimal one
+    let mut searcher: Searcher<Manager<_>> = Searcher::new(Node(0));
+    searcher.manager.weight = 5;
+    let costs: Vec<_> = searcher.by_ref().map(|route| route.total_cost).collect();
+    assert_eq!(costs.last(), Some(&11));
+    assert_eq!(searcher.manager.weight, 1);
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_save() {
+    use crate::*;
+
+    #[derive(Clone, Debug, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+    struct Pos(i32, i32);
+
+    impl CostSearchable for Pos {
+        type Cost = i32;
+
+        fn next_states_with_costs(&self) -> impl Iterator<Item = (Self, Self::Cost)> {
+            let &Pos(x, y) = self;
+            [
+                (Pos(x + 1, y), 1),
+                (Pos(x, y + 1), 1),
+                (Pos(x + 1, y + 1), 4),
+            ]
+            .into_iter()
+        }
+    }
+
+    impl SolutionIdentifiable for Pos {
+        fn is_solution(&self) -> bool {
+            let &Pos(x, y) = self;
+            x == 3 && y == 3
+        }
+    }
+
+    impl Scoreable for Pos {
+        type Score = i32;
+
+        fn score(&self) -> Self::Score {
+            let &Pos(x, y) = self;
+            (x - 3).abs() + (y - 3).abs()
+        }
+    }
+
+    let mut searcher: Searcher<Manager<_>> = Searcher::new(Pos(0, 0));
+    searcher.manager.weight = 4;
+    assert_eq!(searcher.next().map(|route| route.total_cost), Some(12));
+
+    let mut checkpoint = Vec::new();
+    searcher.save(&mut checkpoint).unwrap();
+    let resumed: Searcher<Manager<Pos>> = Searcher::load(checkpoint.as_slice()).unwrap();
+    assert_eq!(resumed.stats, searcher.stats);
+    assert_eq!(
+        resumed.map(|route| route.total_cost).collect::<Vec<_>>(),
+        searcher.map(|route| route.total_cost).collect::<Vec<_>>()
+    );
+}
@@ -0,0 +1,8 @@
+//! Module containing all focal search (A*ε) based, bounded-suboptimal search managers.
+//!
+//! These managers explore, among all states whose estimated total cost is within a suboptimality
+//! factor of the lowest estimate, the state preferred by [`FocalScoreable`](crate::FocalScoreable)
+//! first; solutions found cost at most the suboptimality factor times as much to reach as an optimal one.
+
+pub mod no_route;
+pub mod route;
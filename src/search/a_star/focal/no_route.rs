@@ -0,0 +1,303 @@
+use std::{
+    collections::{BTreeSet, HashMap},
+    hash::Hash,
+    ops::{Add, Bound, Mul},
+};
+
+use num::{One, Zero};
+
+use crate::{
+    ExplorationManager, FnHeuristic, FocalScoreable, Heuristic, HeuristicExplorationManager,
+    OrderedSearchable, ScoreableHeuristic, ScoredFringe, StateCumulativeCost,
+    UniformCostSearchable,
+};
+
+/// Open list entry; an item paired with its estimated total cost.
+type Entry<S, C> = (C, StateCumulativeCost<S, C>);
+
+/// Focal search based, solution-only yielding, prior state exploration culling search manager.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(
+        serialize = "S: serde::Serialize, S::Cost: serde::Serialize, S::FocalScore: serde::Serialize",
+        deserialize = "S: serde::Deserialize<'de> + Eq + Hash, S::Cost: serde::Deserialize<'de>, S::FocalScore: serde::Deserialize<'de>"
+    ))
+)]
+pub struct Manager<S, H = ScoreableHeuristic>
+where
+    S: FocalScoreable + UniformCostSearchable,
+    H: Heuristic<S>,
+{
+    best_costs: HashMap<S, S::Cost>,
+    entries: HashMap<usize, Entry<S, S::Cost>>,
+    open: BTreeSet<(S::Cost, usize)>,
+    focal: ScoredFringe<usize, S::FocalScore>,
+    focal_bound: Option<S::Cost>,
+    next_id: usize,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    heuristic: H,
+
+    /// Suboptimality factor applied to the lowest estimated total cost to determine which states
+    /// may be explored in order of their focal score. Defaults to one, performing A* search with ties
+    /// broken by focal score; solutions found cost at most `suboptimality` times as much to reach as
+    /// an optimal one. Being a cost, the factor of integer costs is a whole number; use a fractional
+    /// cost type such as [`num::rational::Ratio`] for factors like 1.5.
+    pub suboptimality: S::Cost,
+}
+
+impl<S, H> Manager<S, H>
+where
+    S: FocalScoreable + UniformCostSearchable + Clone + Eq + Hash,
+    S::Cost: Add<S::Cost, Output = S::Cost>
+        + Mul<S::Cost, Output = S::Cost>
+        + Zero
+        + One
+        + Clone
+        + From<H::Score>,
+    H: Heuristic<S>,
+{
+    fn new(initial_states: Vec<S>, heuristic: H) -> Self {
+        let mut manager = Self {
+            best_costs: HashMap::new(),
+            entries: HashMap::new(),
+            open: BTreeSet::new(),
+            focal: ScoredFringe::new(),
+            focal_bound: None,
+            next_id: 0,
+            heuristic,
+            suboptimality: S::Cost::one(),
+        };
+        for state in initial_states {
+            if manager
+                .best_costs
+                .insert(state.clone(), S::Cost::zero())
+                .is_none()
+            {
+                manager.push(StateCumulativeCost {
+                    state,
+                    cumulative_cost: S::Cost::zero(),
+                });
+            }
+        }
+        manager
+    }
+
+    fn push(&mut self, item: StateCumulativeCost<S, S::Cost>) {
+        let estimate =
+            S::Cost::from(self.heuristic.score(&item.state)) + item.cumulative_cost.clone();
+        let id = self.next_id;
+        self.next_id += 1;
+        if self
+            .focal_bound
+            .as_ref()
+            .is_some_and(|bound| estimate <= *bound)
+        {
+            self.focal.push(OrderedSearchable {
+                state: id,
+                score: item.state.focal_score(),
+            });
+        }
+        self.open.insert((estimate.clone(), id));
+        self.entries.insert(id, (estimate, item));
+    }
+
+    /// Recompute the focal bound from the lowest estimate in the open list, moving states that fall
+    /// within a raised bound into the focal list.
+    fn update_focal_bound(&mut self) -> Option<S::Cost> {
+        let (lowest_estimate, _) = self.open.first()?;
+        let bound = lowest_estimate.clone() * self.suboptimality.clone();
+        let lower = match self.focal_bound.take() {
+            Some(previous) if previous >= bound => {
+                self.focal_bound = Some(bound.clone());
+                return Some(bound);
+            }
+            Some(previous) => Bound::Excluded((previous, usize::MAX)),
+            None => Bound::Unbounded,
+        };
+        for (_, id) in self
+            .open
+            .range((lower, Bound::Included((bound.clone(), usize::MAX))))
+        {
+            let (_, item) = &self.entries[id];
+            self.focal.push(OrderedSearchable {
+                state: *id,
+                score: item.state.focal_score(),
+            });
+        }
+        self.focal_bound = Some(bound.clone());
+        Some(bound)
+    }
+}
+
+impl<S, H> ExplorationManager for Manager<S, H>
+where
+    S: FocalScoreable + UniformCostSearchable + Clone + Eq + Hash,
+    S::Cost: Add<S::Cost, Output = S::Cost>
+        + Mul<S::Cost, Output = S::Cost>
+        + Zero
+        + One
+        + Clone
+        + From<H::Score>,
+    H: Heuristic<S>,
+{
+    type State = S;
+    type YieldResult = S;
+
+    type FringeItem = StateCumulativeCost<S, S::Cost>;
+
+    type CurrentStateContext = S::Cost;
+
+    type NextStatesIterItem = (S, S::Cost);
+
+    fn initialize(initial_states: Vec<S>) -> Self {
+        Self::new(initial_states, H::default())
+    }
+
+    fn pop_state(&mut self) -> Option<Self::FringeItem> {
+        loop {
+            let bound = self.update_focal_bound()?;
+            let id = self.focal.pop()?.state;
+            let Some((estimate, item)) = self.entries.remove(&id) else {
+                // already explored through a duplicate focal list entry
+                continue;
+            };
+            if estimate > bound {
+                // the bound has been lowered since this entry was added; it will be added again
+                // once the bound rises past its estimate
+                self.entries.insert(id, (estimate, item));
+                continue;
+            }
+            self.open.remove(&(estimate, id));
+            // skip stale entries superseded by a cheaper path to the same state
+            if self
+                .best_costs
+                .get(&item.state)
+                .is_some_and(|best| item.cumulative_cost > *best)
+            {
+                continue;
+            }
+            return Some(item);
+        }
+    }
+
+    fn prepare_result_from(&self, item: Self::FringeItem) -> Self::YieldResult {
+        item.state
+    }
+
+    fn valid_state(&mut self, item: &Self::FringeItem) -> bool {
+        if self
+            .best_costs
+            .get(&item.state)
+            .is_some_and(|best| item.cumulative_cost >= *best)
+        {
+            return false;
+        }
+        self.best_costs
+            .insert(item.state.clone(), item.cumulative_cost.clone());
+        true
+    }
+
+    fn place_state(&mut self, item: Self::FringeItem) {
+        self.push(item);
+    }
+
+    fn register_current_state(&mut self, item: &Self::FringeItem) -> Self::CurrentStateContext {
+        item.cumulative_cost.clone()
+    }
+
+    fn prepare_state(
+        &self,
+        cumulative_cost: &Self::CurrentStateContext,
+        (state, traversal_cost): Self::NextStatesIterItem,
+    ) -> Self::FringeItem {
+        StateCumulativeCost {
+            state,
+            cumulative_cost: cumulative_cost.clone() + traversal_cost,
+        }
+    }
+
+    fn next_states_iter(
+        current_state: &Self::FringeItem,
+    ) -> impl Iterator<Item = Self::NextStatesIterItem> {
+        current_state.as_ref().next_states_with_costs()
+    }
+
+    fn fringe_size(&self) -> usize {
+        self.open.len()
+    }
+
+    fn explored_size(&self) -> usize {
+        self.best_costs.len()
+    }
+}
+
+impl<S, C> HeuristicExplorationManager for Manager<S, FnHeuristic<S, C>>
+where
+    S: FocalScoreable + UniformCostSearchable + Clone + Eq + Hash,
+    S::Cost: Add<S::Cost, Output = S::Cost>
+        + Mul<S::Cost, Output = S::Cost>
+        + Zero
+        + One
+        + Clone
+        + From<C>,
+    C: Ord + Default,
+{
+    type Score = C;
+
+    fn initialize_with_heuristic(initial_states: Vec<S>, heuristic: FnHeuristic<S, C>) -> Self {
+        Self::new(initial_states, heuristic)
+    }
+
+    fn set_heuristic(&mut self, heuristic: FnHeuristic<S, C>) {
+        self.heuristic = heuristic;
+    }
+}
+
+#[test]
+fn test() {
+    use crate::*;
+
+    #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+    struct Pos(i32, i32);
+
+    impl CostSearchable for Pos {
+        type Cost = i32;
+
+        fn next_states_with_costs(&self) -> impl Iterator<Item = (Self, Self::Cost)> {
+            let &Pos(x, y) = self;
+            [Pos(x - 1, y), Pos(x, y - 1), Pos(x + 1, y), Pos(x, y + 1)]
+                .into_iter()
+                .map(|s| (s, 1))
+        }
+    }
+
+    impl SolutionIdentifiable for Pos {
+        fn is_solution(&self) -> bool {
+            let &Pos(x, y) = self;
+            x == 5 && y == 5
+        }
+    }
+
+    impl Scoreable for Pos {
+        type Score = i32;
+
+        fn score(&self) -> Self::Score {
+            let &Pos(x, y) = self;
+            (x - 5).abs() + (y - 5).abs()
+        }
+    }
+
+    impl FocalScoreable for Pos {
+        type FocalScore = i32;
+
+        fn focal_score(&self) -> Self::FocalScore {
+            let &Pos(x, y) = self;
+            (x - 5).abs().max((y - 5).abs())
+        }
+    }
+
+    let mut searcher: Searcher<Manager<_>> = Searcher::new(Pos(0, 0));
+    searcher.manager.suboptimality = 2;
+    assert_eq!(searcher.next(), Some(Pos(5, 5)));
+}
@@ -0,0 +1,471 @@
+use std::{
+    collections::{BTreeSet, HashMap},
+    hash::Hash,
+    ops::{Add, Bound, Mul, Sub},
+    sync::Arc,
+};
+
+use num::{One, Zero};
+
+use crate::{
+    prepare_cost_route_from_state_parent_map, CostRoute, ExplorationManager, FnHeuristic,
+    FocalScoreable, Heuristic, HeuristicExplorationManager, NodeItem, Nodes, OrderedSearchable,
+    ScoreableHeuristic, ScoredFringe, StateParentCumulativeCost, UniformCostSearchable,
+};
+
+/// Focal search based, solution-route yielding, prior state exploration culling search manager.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(
+        serialize = "S: serde::Serialize, S::Cost: serde::Serialize, S::FocalScore: serde::Serialize",
+        deserialize = "S: serde::Deserialize<'de> + Eq + Hash, S::Cost: serde::Deserialize<'de>, S::FocalScore: serde::Deserialize<'de>"
+    ))
+)]
+pub struct Manager<S, H = ScoreableHeuristic>
+where
+    S: FocalScoreable + UniformCostSearchable,
+    H: Heuristic<S>,
+{
+    explored: Nodes<S, StateParentCumulativeCost<S, S::Cost>>,
+    estimates: HashMap<usize, S::Cost>,
+    open: BTreeSet<(S::Cost, usize)>,
+    focal: ScoredFringe<usize, S::FocalScore>,
+    focal_bound: Option<S::Cost>,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    heuristic: H,
+
+    /// Suboptimality factor applied to the lowest estimated total cost to determine which states
+    /// may be explored in order of their focal score. Defaults to one, performing A* search with ties
+    /// broken by focal score; solutions found cost at most `suboptimality` times as much to reach as
+    /// an optimal one. Being a cost, the factor of integer costs is a whole number; use a fractional
+    /// cost type such as [`num::rational::Ratio`] for factors like 1.5.
+    pub suboptimality: S::Cost,
+}
+
+impl<S, H> Manager<S, H>
+where
+    S: FocalScoreable + UniformCostSearchable + Clone + Eq + Hash,
+    S::Cost: Add<S::Cost, Output = S::Cost>
+        + Sub<S::Cost, Output = S::Cost>
+        + Mul<S::Cost, Output = S::Cost>
+        + Zero
+        + One
+        + Clone
+        + From<H::Score>,
+    H: Heuristic<S>,
+{
+    fn new(initial_states: Vec<S>, heuristic: H) -> Self {
+        let mut manager = Self {
+            explored: Nodes::new(),
+            estimates: HashMap::new(),
+            open: BTreeSet::new(),
+            focal: ScoredFringe::new(),
+            focal_bound: None,
+            heuristic,
+            suboptimality: S::Cost::one(),
+        };
+        for state in initial_states {
+            if let Some(index) = manager.explored.insert(StateParentCumulativeCost {
+                state: Arc::new(state),
+                parent: None,
+                cumulative_cost: S::Cost::zero(),
+            }) {
+                manager.push(index);
+            }
+        }
+        manager
+    }
+
+    fn push(&mut self, index: usize) {
+        let item = &self.explored[index];
+        let estimate =
+            S::Cost::from(self.heuristic.score(&item.state)) + item.cumulative_cost.clone();
+        if self
+            .focal_bound
+            .as_ref()
+            .is_some_and(|bound| estimate <= *bound)
+        {
+            self.focal.push(OrderedSearchable {
+                state: index,
+                score: item.state.focal_score(),
+            });
+        }
+        self.open.insert((estimate.clone(), index));
+        self.estimates.insert(index, estimate);
+    }
+
+    /// Recompute the focal bound from the lowest estimate in the open list, moving states that fall
+    /// within a raised bound into the focal list.
+    fn update_focal_bound(&mut self) -> Option<S::Cost> {
+        let (lowest_estimate, _) = self.open.first()?;
+        let bound = lowest_estimate.clone() * self.suboptimality.clone();
+        let lower = match self.focal_bound.take() {
+            Some(previous) if previous >= bound => {
+                self.focal_bound = Some(bound.clone());
+                return Some(bound);
+            }
+            Some(previous) => Bound::Excluded((previous, usize::MAX)),
+            None => Bound::Unbounded,
+        };
+        for (_, index) in self
+            .open
+            .range((lower, Bound::Included((bound.clone(), usize::MAX))))
+        {
+            self.focal.push(OrderedSearchable {
+                state: *index,
+                score: self.explored[*index].state.focal_score(),
+            });
+        }
+        self.focal_bound = Some(bound.clone());
+        Some(bound)
+    }
+}
+
+impl<S, H> ExplorationManager for Manager<S, H>
+where
+    S: FocalScoreable + UniformCostSearchable + Clone + Eq + Hash,
+    S::Cost: Add<S::Cost, Output = S::Cost>
+        + Sub<S::Cost, Output = S::Cost>
+        + Mul<S::Cost, Output = S::Cost>
+        + Zero
+        + One
+        + Clone
+        + From<H::Score>,
+    H: Heuristic<S>,
+{
+    type State = S;
+    type YieldResult = CostRoute<S, S::Cost>;
+
+    type FringeItem = NodeItem<StateParentCumulativeCost<S, S::Cost>>;
+
+    type CurrentStateContext = (usize, S::Cost);
+
+    type NextStatesIterItem = (S, S::Cost);
+
+    fn initialize(initial_states: Vec<S>) -> Self {
+        Self::new(initial_states, H::default())
+    }
+
+    fn pop_state(&mut self) -> Option<Self::FringeItem> {
+        loop {
+            let bound = self.update_focal_bound()?;
+            let index = self.focal.pop()?.state;
+            let Some(estimate) = self.estimates.remove(&index) else {
+                // already explored through a duplicate focal list entry
+                continue;
+            };
+            if estimate > bound {
+                // the bound has been lowered since this entry was added; it will be added again
+                // once the bound rises past its estimate
+                self.estimates.insert(index, estimate);
+                continue;
+            }
+            self.open.remove(&(estimate, index));
+            let item = &self.explored[index];
+            // skip stale entries superseded by a cheaper path to the same state
+            if self.explored.index_of(&item.state) != Some(index) {
+                continue;
+            }
+            return Some(NodeItem {
+                item: item.clone(),
+                node: Some(index),
+            });
+        }
+    }
+
+    fn prepare_result_from(&self, NodeItem { item, .. }: Self::FringeItem) -> Self::YieldResult {
+        prepare_cost_route_from_state_parent_map(&self.explored, item)
+    }
+
+    fn valid_state(&mut self, NodeItem { item, .. }: &Self::FringeItem) -> bool {
+        self.explored
+            .index_of(&item.state)
+            .is_none_or(|best| item.cumulative_cost < self.explored[best].cumulative_cost)
+    }
+
+    fn place_state(&mut self, NodeItem { item, .. }: Self::FringeItem) {
+        let index = self.explored.push(item);
+        self.push(index);
+    }
+
+    fn register_current_state(
+        &mut self,
+        NodeItem { item, node }: &Self::FringeItem,
+    ) -> Self::CurrentStateContext {
+        let index = node.expect("Popped items always carry their node");
+        (index, item.cumulative_cost.clone())
+    }
+
+    fn prepare_state(
+        &self,
+        (parent, cumulative_cost): &Self::CurrentStateContext,
+        (state, traversal_cost): Self::NextStatesIterItem,
+    ) -> Self::FringeItem {
+        NodeItem {
+            item: StateParentCumulativeCost {
+                state: Arc::new(state),
+                parent: Some(*parent),
+                cumulative_cost: cumulative_cost.clone() + traversal_cost,
+            },
+            node: None,
+        }
+    }
+
+    fn next_states_iter(
+        current_state: &Self::FringeItem,
+    ) -> impl Iterator<Item = Self::NextStatesIterItem> {
+        current_state.as_ref().next_states_with_costs()
+    }
+
+    fn fringe_size(&self) -> usize {
+        self.open.len()
+    }
+
+    fn explored_size(&self) -> usize {
+        self.explored.states_len()
+    }
+}
+
+impl<S, C> HeuristicExplorationManager for Manager<S, FnHeuristic<S, C>>
+where
+    S: FocalScoreable + UniformCostSearchable + Clone + Eq + Hash,
+    S::Cost: Add<S::Cost, Output = S::Cost>
+        + Sub<S::Cost, Output = S::Cost>
+        + Mul<S::Cost, Output = S::Cost>
+        + Zero
+        + One
+        + Clone
+        + From<C>,
+    C: Ord + Default,
+{
+    type Score = C;
+
+    fn initialize_with_heuristic(initial_states: Vec<S>, heuristic: FnHeuristic<S, C>) -> Self {
+        Self::new(initial_states, heuristic)
+    }
+
+    fn set_heuristic(&mut self, heuristic: FnHeuristic<S, C>) {
+        self.heuristic = heuristic;
+    }
+}
+
+#[test]
+fn test() {
+    use crate::*;
+
+    #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+    struct Pos(i32, i32);
+
+    impl CostSearchable for Pos {
+        type Cost = i32;
+
+        fn next_states_with_costs(&self) -> impl Iterator<Item = (Self, Self::Cost)> {
+            let &Pos(x, y) = self;
+            [Pos(x - 1, y), Pos(x, y - 1), Pos(x + 1, y), Pos(x, y + 1)]
+                .into_iter()
+                .map(|s| (s, 1))
+        }
+    }
+
+    impl SolutionIdentifiable for Pos {
+        fn is_solution(&self) -> bool {
+            let &Pos(x, y) = self;
+            x == 5 && y == 5
+        }
+    }
+
+    impl Scoreable for Pos {
+        type Score = i32;
+
+        fn score(&self) -> Self::Score {
+            let &Pos(x, y) = self;
+            (x - 5).abs() + (y - 5).abs()
+        }
+    }
+
+    impl FocalScoreable for Pos {
+        type FocalScore = i32;
+
+        fn focal_score(&self) -> Self::FocalScore {
+            let &Pos(x, y) = self;
+            (x - 5).abs().max((y - 5).abs())
+        }
+    }
+
+    let mut searcher: Searcher<Manager<_>> = Searcher::new(Pos(0, 0));
+    searcher.manager.suboptimality = 2;
+    assert_eq!(
+        searcher.next().map(|route| route.states),
+        Some(vec![
+            Pos(0, 0),
+            Pos(1, 0),
+            Pos(1, 1),
+            Pos(2, 1),
+            Pos(2, 2),
+            Pos(3, 2),
+            Pos(3, 3),
+            Pos(4, 3),
+            Pos(4, 4),
+            Pos(5, 4),
+            Pos(5, 5)
+        ])
+    );
+}
+
+#[test]
+fn test_fractional_suboptimality() {
+    use num::rational::Ratio;
+
+    use crate::*;
+
+    #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+    struct Pos(i32, i32);
+
+    impl CostSearchable for Pos {
+        type Cost = Ratio<i32>;
+
+        fn next_states_with_costs(&self) -> impl Iterator<Item = (Self, Self::Cost)> {
+            let &Pos(x, y) = self;
+            [Pos(x - 1, y), Pos(x, y - 1), Pos(x + 1, y), Pos(x, y + 1)]
+                .into_iter()
+                .map(|s| (s, Ratio::from(1)))
+        }
+    }
+
+    impl SolutionIdentifiable for Pos {
+        fn is_solution(&self) -> bool {
+            let &Pos(x, y) = self;
+            x == 5 && y == 5
+        }
+    }
+
+    impl Scoreable for Pos {
+        type Score = i32;
+
+        fn score(&self) -> Self::Score {
+            let &Pos(x, y) = self;
+            (x - 5).abs() + (y - 5).abs()
+        }
+    }
+
+    impl FocalScoreable for Pos {
+        type FocalScore = i32;
+
+        fn focal_score(&self) -> Self::FocalScore {
+            let &Pos(x, y) = self;
+            (x - 5).abs().max((y - 5).abs())
+        }
+    }
+
+    let mut searcher: Searcher<Manager<_>> = Searcher::new(Pos(0, 0));
+    searcher.manager.suboptimality = Ratio::new(3, 2);
+    let route = searcher.next().unwrap();
+    assert_eq!(route.states.last(), Some(&Pos(5, 5)));
+    assert!(route.total_cost <= Ratio::from(15));
+}
+
+#[test]
+fn test_heuristic() {
+    use crate::*;
+
+    #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+    struct Pos(i32, i32);
+
+    impl UniformCostSearchable for Pos {
+        type Cost = i32;
+
+        fn next_states_with_costs(&self) -> impl Iterator<Item = (Self, Self::Cost)> {
+            let &Pos(x, y) = self;
+            [Pos(x - 1, y), Pos(x, y - 1), Pos(x + 1, y), Pos(x, y + 1)]
+                .into_iter()
+                .map(|s| (s, 1))
+        }
+    }
+
+    impl FocalScoreable for Pos {
+        type FocalScore = i32;
+
+        fn focal_score(&self) -> Self::FocalScore {
+            let &Pos(x, y) = self;
+            (x - 3).abs().max((y - 2).abs())
+        }
+    }
+
+    let goal = Pos(3, 2);
+    let mut searcher =
+        Searcher::<Manager<_, FnHeuristic<_, _>>>::new_with_heuristic(Pos(0, 0), |&Pos(x, y)| {
+            (x - 3).abs() + (y - 2).abs()
+        })
+        .with_goal_predicate(|pos: &Pos| *pos == goal);
+    searcher.manager.suboptimality = 2;
+    let route = searcher.next().unwrap();
+    assert_eq!(route.states.last(), Some(&Pos(3, 2)));
+    assert!(route.total_cost <= 10);
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_save() {
+    use crate::*;
+
+    #[derive(Clone, Debug, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+    struct Pos(i32, i32);
+
+    impl CostSearchable for Pos {
+        type Cost = i32;
+
+        fn next_states_with_costs(&self) -> impl Iterator<Item = (Self, Self::Cost)> {
+            let &Pos(x, y) = self;
+            [Pos(x - 1, y), Pos(x, y - 1), Pos(x + 1, y), Pos(x, y + 1)]
+                .into_iter()
+                .map(|s| (s, 1))
+        }
+    }
+
+    impl SolutionIdentifiable for Pos {
+        fn is_solution(&self) -> bool {
+            let &Pos(x, y) = self;
+            x == 5 && y == 5
+        }
+    }
+
+    impl Scoreable for Pos {
+        type Score = i32;
+
+        fn score(&self) -> Self::Score {
+            let &Pos(x, y) = self;
+            (x - 5).abs() + (y - 5).abs()
+        }
+    }
+
+    impl FocalScoreable for Pos {
+        type FocalScore = i32;
+
+        fn focal_score(&self) -> Self::FocalScore {
+            let &Pos(x, y) = self;
+            (x - 5).abs().max((y - 5).abs())
+        }
+    }
+
+    let mut uninterrupted: Searcher<Manager<_>> = Searcher::new(Pos(0, 0));
+    uninterrupted.manager.suboptimality = 2;
+    let uninterrupted = uninterrupted.next();
+    assert!(uninterrupted.is_some());
+
+    let mut searcher: Searcher<Manager<_>> = Searcher::new(Pos(0, 0));
+    searcher.manager.suboptimality = 2;
+    searcher.max_expansions = Some(4);
+    assert_eq!(
+        searcher.try_next(),
+        SearchOutcome::LimitReached(SearchLimit::Expansions)
+    );
+
+    let mut checkpoint = Vec::new();
+    searcher.save(&mut checkpoint).unwrap();
+    let mut resumed: Searcher<Manager<Pos>> = Searcher::load(checkpoint.as_slice()).unwrap();
+    assert_eq!(resumed.stats, searcher.stats);
+    resumed.max_expansions = None;
+    assert_eq!(resumed.manager.suboptimality, 2);
+    assert_eq!(resumed.next(), uninterrupted);
+}
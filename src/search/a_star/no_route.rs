@@ -1,4 +1,4 @@
 //! Module containing all A* based, solution-only yielding search managers.
 
 pub mod hashable;
-pub mod unhashable;
\ No newline at end of file
+pub mod unhashable;
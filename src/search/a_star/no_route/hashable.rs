@@ -1,75 +1,174 @@
 use std::{
-    collections::{BinaryHeap, HashSet},
+    collections::{HashMap, HashSet},
     hash::Hash,
-    ops::Add,
+    ops::{Add, Mul},
 };
 
-use num::Zero;
+use num::{One, Zero};
 
 use crate::{
-    CostSearchable, ExplorationManager, OrderedSearchable, Scoreable, StateCumulativeCost,
+    ExplorationManager, FnHeuristic, Heuristic, HeuristicExplorationManager, OrderedSearchable,
+    ScoreableHeuristic, ScoredFringe, SearchLimit, StateCumulativeCost, UniformCostSearchable,
 };
 
 /// A* based, solution-only yielding, prior state exploration culling search space manager.
-pub struct Manager<S>
+///
+/// Keeps track of the cheapest known cumulative cost of every explored state; states reached again
+/// through a cheaper path replace their previous fringe entries.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(
+        serialize = "S: serde::Serialize, S::Cost: serde::Serialize",
+        deserialize = "S: serde::Deserialize<'de> + Eq + Hash, S::Cost: serde::Deserialize<'de>"
+    ))
+)]
+pub struct Manager<S, H = ScoreableHeuristic>
 where
-    S: Scoreable,
+    S: UniformCostSearchable,
+    H: Heuristic<S>,
 {
-    explored: HashSet<S>,
-    fringe: BinaryHeap<OrderedSearchable<StateCumulativeCost<S, S::Score>, S::Score>>,
+    best_costs: HashMap<S, S::Cost>,
+    closed: HashSet<S>,
+    fringe: ScoredFringe<StateCumulativeCost<S, S::Cost>, S::Cost>,
+    cost_limited: bool,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    heuristic: H,
+
+    /// Toggle re-opening of already expanded states on. By default, a state is never expanded twice.
+    /// Enable this flag if your heuristic is admissible but inconsistent, to have states expanded
+    /// again whenever a cheaper path to them is found.
+    pub reopen_closed: bool,
+
+    /// Weight applied to the heuristic score of every state. Defaults to one, performing regular A* search.
+    /// Set this above one to perform weighted A* search, which explores towards solutions more
+    /// greedily; solutions found then cost at most `weight` times as much to reach as an optimal one.
+    /// Being a cost, the weight of integer costs is a whole number; use a fractional cost type such as
+    /// [`num::rational::Ratio`] for weights like 1.5.
+    pub weight: S::Cost,
+
+    /// Maximum estimated total cost of routes to explore. By default, no limit is applied.
+    /// States whose cumulative cost plus heuristic score exceeds this are discarded, and the search
+    /// stops with [`SearchLimit::Cost`] once no states within the limit remain.
+    pub max_cost: Option<S::Cost>,
+}
+
+impl<S, H> Manager<S, H>
+where
+    S: UniformCostSearchable + Clone + Eq + Hash,
+    S::Cost: Add<S::Cost, Output = S::Cost>
+        + Mul<S::Cost, Output = S::Cost>
+        + Zero
+        + One
+        + Clone
+        + From<H::Score>,
+    H: Heuristic<S>,
+{
+    fn new(initial_states: Vec<S>, heuristic: H) -> Self {
+        let mut best_costs = HashMap::new();
+        let fringe = initial_states
+            .into_iter()
+            .filter(|state| best_costs.insert(state.clone(), S::Cost::zero()).is_none())
+            .map(|state| OrderedSearchable {
+                score: S::Cost::from(heuristic.score(&state)),
+                state: StateCumulativeCost {
+                    state,
+                    cumulative_cost: S::Cost::zero(),
+                },
+            })
+            .collect();
+        Self {
+            best_costs,
+            closed: HashSet::new(),
+            fringe,
+            cost_limited: false,
+            heuristic,
+            reopen_closed: false,
+            weight: S::Cost::one(),
+            max_cost: None,
+        }
+    }
 }
 
-impl<S> ExplorationManager for Manager<S>
+impl<S, H> ExplorationManager for Manager<S, H>
 where
-    S: CostSearchable + Clone + Eq + Hash,
-    S::Score: Add<S::Score, Output = S::Score> + Zero + Clone,
+    S: UniformCostSearchable + Clone + Eq + Hash,
+    S::Cost: Add<S::Cost, Output = S::Cost>
+        + Mul<S::Cost, Output = S::Cost>
+        + Zero
+        + One
+        + Clone
+        + From<H::Score>,
+    H: Heuristic<S>,
 {
     type State = S;
     type YieldResult = S;
 
-    type FringeItem = StateCumulativeCost<S, S::Score>;
+    type FringeItem = StateCumulativeCost<S, S::Cost>;
 
-    type CurrentStateContext = S::Score;
+    type CurrentStateContext = S::Cost;
 
-    type NextStatesIterItem = (S, S::Score);
+    type NextStatesIterItem = (S, S::Cost);
 
-    fn initialize(initial_state: S) -> Self {
-        let score = initial_state.score();
-        Self {
-            explored: HashSet::from([initial_state.clone()]),
-            fringe: BinaryHeap::from([OrderedSearchable {
-                score,
-                state: StateCumulativeCost {
-                    state: initial_state,
-                    cumulative_cost: S::Score::zero(),
-                },
-            }]),
-        }
+    fn initialize(initial_states: Vec<S>) -> Self {
+        Self::new(initial_states, H::default())
     }
 
     fn pop_state(&mut self) -> Option<Self::FringeItem> {
-        self.fringe.pop().map(|s| s.state)
+        loop {
+            let item = self.fringe.pop()?.state;
+            // skip stale entries superseded by a cheaper path to the same state
+            if self
+                .best_costs
+                .get(&item.state)
+                .is_some_and(|best| item.cumulative_cost > *best)
+            {
+                continue;
+            }
+            return Some(item);
+        }
     }
 
     fn prepare_result_from(&self, item: Self::FringeItem) -> Self::YieldResult {
         item.state
     }
 
+    fn exceeds_limit(&mut self, item: &Self::FringeItem) -> bool {
+        let exceeds = self.max_cost.as_ref().is_some_and(|max_cost| {
+            S::Cost::from(self.heuristic.score(&item.state)) + item.cumulative_cost.clone()
+                > *max_cost
+        });
+        self.cost_limited |= exceeds;
+        exceeds
+    }
+
     fn valid_state(&mut self, item: &Self::FringeItem) -> bool {
-        if !self.explored.contains(&item.state) {
-            self.explored.insert(item.state.clone());
-            true
-        } else {
-            false
+        if self
+            .best_costs
+            .get(&item.state)
+            .is_some_and(|best| item.cumulative_cost >= *best)
+        {
+            return false;
+        }
+        if self.closed.contains(&item.state) {
+            if !self.reopen_closed {
+                return false;
+            }
+            self.closed.remove(&item.state);
         }
+        self.best_costs
+            .insert(item.state.clone(), item.cumulative_cost.clone());
+        true
     }
 
     fn place_state(&mut self, item: Self::FringeItem) {
-        let score = item.state.score() + item.cumulative_cost.clone();
+        let score = S::Cost::from(self.heuristic.score(&item.state)) * self.weight.clone()
+            + item.cumulative_cost.clone();
         self.fringe.push(OrderedSearchable { state: item, score })
     }
 
     fn register_current_state(&mut self, item: &Self::FringeItem) -> Self::CurrentStateContext {
+        self.closed.insert(item.state.clone());
         item.cumulative_cost.clone()
     }
 
@@ -84,8 +183,44 @@ where
         }
     }
 
-    fn next_states_iter(current_state: &S) -> impl Iterator<Item = Self::NextStatesIterItem> {
-        current_state.next_states_with_costs()
+    fn next_states_iter(
+        current_state: &Self::FringeItem,
+    ) -> impl Iterator<Item = Self::NextStatesIterItem> {
+        current_state.as_ref().next_states_with_costs()
+    }
+
+    fn fringe_size(&self) -> usize {
+        self.fringe.len()
+    }
+
+    fn explored_size(&self) -> usize {
+        self.best_costs.len()
+    }
+
+    fn limit_reached(&self) -> Option<SearchLimit> {
+        self.cost_limited.then_some(SearchLimit::Cost)
+    }
+}
+
+impl<S, C> HeuristicExplorationManager for Manager<S, FnHeuristic<S, C>>
+where
+    S: UniformCostSearchable + Clone + Eq + Hash,
+    S::Cost: Add<S::Cost, Output = S::Cost>
+        + Mul<S::Cost, Output = S::Cost>
+        + Zero
+        + One
+        + Clone
+        + From<C>,
+    C: Ord + Default,
+{
+    type Score = C;
+
+    fn initialize_with_heuristic(initial_states: Vec<S>, heuristic: FnHeuristic<S, C>) -> Self {
+        Self::new(initial_states, heuristic)
+    }
+
+    fn set_heuristic(&mut self, heuristic: FnHeuristic<S, C>) {
+        self.heuristic = heuristic;
     }
 }
 
@@ -113,7 +248,9 @@ fn test() {
     }
 
     impl CostSearchable for Pos {
-        fn next_states_with_costs(&self) -> impl Iterator<Item = (Self, Self::Score)> {
+        type Cost = i32;
+
+        fn next_states_with_costs(&self) -> impl Iterator<Item = (Self, Self::Cost)> {
             let &Pos(x, y) = self;
             [Pos(x - 1, y), Pos(x, y - 1), Pos(x + 1, y), Pos(x, y + 1)]
                 .into_iter()
@@ -124,3 +261,52 @@ fn test() {
     let mut searcher: Searcher<Manager<_>> = Searcher::new(Pos(0, 0));
     assert_eq!(searcher.next(), Some(Pos(5, 5)));
 }
+
+#[test]
+fn test_max_cost() {
+    use crate::*;
+
+    #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+    struct Pos(i32, i32);
+
+    impl SolutionIdentifiable for Pos {
+        fn is_solution(&self) -> bool {
+            let &Pos(x, y) = self;
+            x == 5 && y == 5
+        }
+    }
+
+    impl Scoreable for Pos {
+        type Score = i32;
+
+        fn score(&self) -> Self::Score {
+            let &Pos(x, y) = self;
+            (x - 5).abs() + (y - 5).abs()
+        }
+    }
+
+    impl CostSearchable for Pos {
+        type Cost = i32;
+
+        fn next_states_with_costs(&self) -> impl Iterator<Item = (Self, Self::Cost)> {
+            let &Pos(x, y) = self;
+            [Pos(x - 1, y), Pos(x, y - 1), Pos(x + 1, y), Pos(x, y + 1)]
+                .into_iter()
+                .map(|s| (s, 1))
+        }
+    }
+
+    let mut searcher: Searcher<Manager<_>> = Searcher::new(Pos(0, 0));
+    searcher.manager.max_cost = Some(8);
+    assert_eq!(
+        searcher.try_next(),
+        SearchOutcome::LimitReached(SearchLimit::Cost)
+    );
+    assert_eq!(searcher.stats.states_generated, 4);
+    assert_eq!(searcher.stats.limits_culled, 4);
+    assert_eq!(searcher.stats.duplicates_culled, 0);
+
+    let mut searcher: Searcher<Manager<_>> = Searcher::new(Pos(0, 0));
+    searcher.manager.max_cost = Some(10);
+    assert_eq!(searcher.try_next(), SearchOutcome::Solution(Pos(5, 5)));
+}
@@ -1,44 +1,102 @@
-use std::{collections::BinaryHeap, ops::Add};
+use std::{
+    collections::BinaryHeap,
+    ops::{Add, Mul},
+};
 
-use num::Zero;
+use num::{One, Zero};
 
 use crate::{
-    CostSearchable, ExplorationManager, OrderedSearchable, Scoreable, StateCumulativeCost,
+    ExplorationManager, FnHeuristic, Heuristic, HeuristicExplorationManager, OrderedSearchable,
+    ScoreableHeuristic, SearchLimit, StateCumulativeCost, UniformCostSearchable,
 };
 
 /// A* based, solution-only yielding, unoptimized search space manager.
-pub struct Manager<S>
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(
+        serialize = "S: serde::Serialize, S::Cost: serde::Serialize",
+        deserialize = "S: serde::Deserialize<'de>, S::Cost: serde::Deserialize<'de>"
+    ))
+)]
+pub struct Manager<S, H = ScoreableHeuristic>
+where
+    S: UniformCostSearchable,
+    H: Heuristic<S>,
+{
+    #[allow(clippy::type_complexity)]
+    fringe: BinaryHeap<OrderedSearchable<StateCumulativeCost<S, S::Cost>, S::Cost>>,
+    cost_limited: bool,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    heuristic: H,
+
+    /// Weight applied to the heuristic score of every state. Defaults to one, performing regular A* search.
+    /// Set this above one to perform weighted A* search, which explores towards solutions more
+    /// greedily; solutions found then cost at most `weight` times as much to reach as an optimal one.
+    /// Being a cost, the weight of integer costs is a whole number; use a fractional cost type such as
+    /// [`num::rational::Ratio`] for weights like 1.5.
+    pub weight: S::Cost,
+
+    /// Maximum estimated total cost of routes to explore. By default, no limit is applied.
+    /// States whose cumulative cost plus heuristic score exceeds this are discarded, and the search
+    /// stops with [`SearchLimit::Cost`] once no states within the limit remain.
+    pub max_cost: Option<S::Cost>,
+}
+
+impl<S, H> Manager<S, H>
 where
-    S: Scoreable,
+    S: UniformCostSearchable,
+    S::Cost: Add<S::Cost, Output = S::Cost>
+        + Mul<S::Cost, Output = S::Cost>
+        + Zero
+        + One
+        + Clone
+        + From<H::Score>,
+    H: Heuristic<S>,
 {
-    fringe: BinaryHeap<OrderedSearchable<StateCumulativeCost<S, S::Score>, S::Score>>,
+    fn new(initial_states: Vec<S>, heuristic: H) -> Self {
+        let fringe = initial_states
+            .into_iter()
+            .map(|state| OrderedSearchable {
+                score: S::Cost::from(heuristic.score(&state)),
+                state: StateCumulativeCost {
+                    state,
+                    cumulative_cost: S::Cost::zero(),
+                },
+            })
+            .collect();
+        Self {
+            fringe,
+            cost_limited: false,
+            heuristic,
+            weight: S::Cost::one(),
+            max_cost: None,
+        }
+    }
 }
 
-impl<S> ExplorationManager for Manager<S>
+impl<S, H> ExplorationManager for Manager<S, H>
 where
-    S: CostSearchable,
-    S::Score: Add<S::Score, Output = S::Score> + Zero + Clone,
+    S: UniformCostSearchable,
+    S::Cost: Add<S::Cost, Output = S::Cost>
+        + Mul<S::Cost, Output = S::Cost>
+        + Zero
+        + One
+        + Clone
+        + From<H::Score>,
+    H: Heuristic<S>,
 {
     type State = S;
     type YieldResult = S;
 
-    type FringeItem = StateCumulativeCost<S, S::Score>;
+    type FringeItem = StateCumulativeCost<S, S::Cost>;
 
-    type CurrentStateContext = S::Score;
+    type CurrentStateContext = S::Cost;
 
-    type NextStatesIterItem = (S, S::Score);
+    type NextStatesIterItem = (S, S::Cost);
 
-    fn initialize(initial_state: S) -> Self {
-        let score = initial_state.score();
-        Self {
-            fringe: BinaryHeap::from([OrderedSearchable {
-                score,
-                state: StateCumulativeCost {
-                    state: initial_state,
-                    cumulative_cost: S::Score::zero(),
-                },
-            }]),
-        }
+    fn initialize(initial_states: Vec<S>) -> Self {
+        Self::new(initial_states, H::default())
     }
 
     fn pop_state(&mut self) -> Option<Self::FringeItem> {
@@ -49,12 +107,22 @@ where
         item.state
     }
 
+    fn exceeds_limit(&mut self, item: &Self::FringeItem) -> bool {
+        let exceeds = self.max_cost.as_ref().is_some_and(|max_cost| {
+            S::Cost::from(self.heuristic.score(&item.state)) + item.cumulative_cost.clone()
+                > *max_cost
+        });
+        self.cost_limited |= exceeds;
+        exceeds
+    }
+
     fn valid_state(&mut self, _item: &Self::FringeItem) -> bool {
         true
     }
 
     fn place_state(&mut self, item: Self::FringeItem) {
-        let score = item.state.score() + item.cumulative_cost.clone();
+        let score = S::Cost::from(self.heuristic.score(&item.state)) * self.weight.clone()
+            + item.cumulative_cost.clone();
         self.fringe.push(OrderedSearchable { state: item, score })
     }
 
@@ -73,8 +141,44 @@ where
         }
     }
 
-    fn next_states_iter(current_state: &S) -> impl Iterator<Item = Self::NextStatesIterItem> {
-        current_state.next_states_with_costs()
+    fn next_states_iter(
+        current_state: &Self::FringeItem,
+    ) -> impl Iterator<Item = Self::NextStatesIterItem> {
+        current_state.as_ref().next_states_with_costs()
+    }
+
+    fn fringe_size(&self) -> usize {
+        self.fringe.len()
+    }
+
+    fn explored_size(&self) -> usize {
+        0
+    }
+
+    fn limit_reached(&self) -> Option<SearchLimit> {
+        self.cost_limited.then_some(SearchLimit::Cost)
+    }
+}
+
+impl<S, C> HeuristicExplorationManager for Manager<S, FnHeuristic<S, C>>
+where
+    S: UniformCostSearchable,
+    S::Cost: Add<S::Cost, Output = S::Cost>
+        + Mul<S::Cost, Output = S::Cost>
+        + Zero
+        + One
+        + Clone
+        + From<C>,
+    C: Ord + Default,
+{
+    type Score = C;
+
+    fn initialize_with_heuristic(initial_states: Vec<S>, heuristic: FnHeuristic<S, C>) -> Self {
+        Self::new(initial_states, heuristic)
+    }
+
+    fn set_heuristic(&mut self, heuristic: FnHeuristic<S, C>) {
+        self.heuristic = heuristic;
     }
 }
 
@@ -102,7 +206,9 @@ fn test() {
     }
 
     impl CostSearchable for Pos {
-        fn next_states_with_costs(&self) -> impl Iterator<Item = (Self, Self::Score)> {
+        type Cost = i32;
+
+        fn next_states_with_costs(&self) -> impl Iterator<Item = (Self, Self::Cost)> {
             let &Pos(x, y) = self;
             [Pos(x - 1, y), Pos(x, y - 1), Pos(x + 1, y), Pos(x, y + 1)]
                 .into_iter()
@@ -112,4 +218,4 @@ fn test() {
 
     let mut searcher: Searcher<Manager<_>> = Searcher::new(Pos(0, 0));
     assert_eq!(searcher.next(), Some(Pos(5, 5)));
-}
\ No newline at end of file
+}
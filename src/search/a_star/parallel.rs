@@ -0,0 +1,699 @@
+use std::{
+    collections::{HashMap, VecDeque},
+    hash::{DefaultHasher, Hash, Hasher},
+    ops::{Add, Sub},
+    panic,
+    sync::{
+        atomic::{AtomicBool, AtomicUsize, Ordering},
+        mpsc::{self, Receiver, Sender},
+        RwLock,
+    },
+    thread,
+    time::Duration,
+};
+
+use num::Zero;
+
+use crate::{
+    Budget, CostRoute, ExplorationManager, FnHeuristic, Heuristic, HeuristicExplorationManager,
+    Incumbent, OrderedSearchable, ScoreableHeuristic, ScoredFringe, SolutionIdentifiable,
+    UniformCostSearchable,
+};
+
+/// State sent to the worker owning it; paired with the state it was reached from and its cumulative cost.
+type Transfer<S, C> = (S, Option<S>, C);
+
+/// Cheapest known cumulative cost of every state explored by a worker, paired with the state it was
+/// reached from.
+type Parents<S, C> = HashMap<S, (Option<S>, C)>;
+
+/// Return the index of the worker owning the state.
+fn owner<S>(state: &S, workers: usize) -> usize
+where
+    S: Hash,
+{
+    let mut hasher = DefaultHasher::new();
+    state.hash(&mut hasher);
+    hasher.finish() as usize % workers
+}
+
+/// Search state shared between all workers during a single call to the searcher.
+struct Shared<'m, S, C, H> {
+    senders: Vec<Sender<Transfer<S, C>>>,
+    incumbent: RwLock<Option<(S, C)>>,
+    heuristic: &'m H,
+    budget: &'m Budget,
+    /// Number of states either in transit between workers or waiting on a worker's fringe.
+    outstanding: AtomicUsize,
+    expanded: AtomicUsize,
+    generated: AtomicUsize,
+    /// Set once any worker runs out of budget, to have the remaining workers stop.
+    interrupted: AtomicBool,
+    /// Set once any worker panics, to have the remaining workers stop.
+    abandoned: AtomicBool,
+}
+
+/// Abandons the search if dropped while its worker panics.
+struct AbandonOnPanic<'s, 'm, S, C, H>(&'s Shared<'m, S, C, H>);
+
+impl<S, C, H> Drop for AbandonOnPanic<'_, '_, S, C, H> {
+    fn drop(&mut self) {
+        if thread::panicking() {
+            self.0.abandoned.store(true, Ordering::SeqCst);
+        }
+    }
+}
+
+impl<S, C, H> Shared<'_, S, C, H>
+where
+    S: Hash,
+    C: Ord + Clone,
+{
+    fn send(&self, transfer: Transfer<S, C>) {
+        let owner = owner(&transfer.0, self.senders.len());
+        self.outstanding.fetch_add(1, Ordering::SeqCst);
+        self.senders[owner]
+            .send(transfer)
+            .expect("Workers only stop once no states remain to be sent, or after a panic");
+    }
+
+    fn finish(&self) {
+        self.outstanding.fetch_sub(1, Ordering::SeqCst);
+    }
+
+    /// Claim one expansion from the budget, returning `false` if the budget has run out.
+    fn expand(&self) -> bool {
+        if self.budget.spent() {
+            return false;
+        }
+        let expanded = self.expanded.fetch_add(1, Ordering::SeqCst);
+        if self.budget.expansions().is_some_and(|max| expanded >= max) {
+            self.expanded.fetch_sub(1, Ordering::SeqCst);
+            return false;
+        }
+        true
+    }
+
+    /// Return `true` if a solution at most as expensive as the estimate has already been found.
+    fn pruned(&self, estimate: &C) -> bool {
+        let incumbent = self
+            .incumbent
+            .read()
+            .expect("Solutions are never offered while panicking");
+        incumbent.as_ref().is_some_and(|(_, cost)| estimate >= cost)
+    }
+
+    fn offer(&self, state: S, cost: C) {
+        let mut incumbent = self
+            .incumbent
+            .write()
+            .expect("Solutions are never offered while panicking");
+        if incumbent.as_ref().is_none_or(|(_, best)| cost < *best) {
+            *incumbent = Some((state, cost));
+        }
+    }
+}
+
+/// One partition of the search space, explored by a single worker thread.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(
+        serialize = "S: serde::Serialize, C: serde::Serialize",
+        deserialize = "S: serde::Deserialize<'de> + Eq + Hash, C: serde::Deserialize<'de> + Ord"
+    ))
+)]
+struct Worker<S, C> {
+    parents: Parents<S, C>,
+    fringe: ScoredFringe<(S, C), C>,
+}
+
+impl<S> Worker<S, S::Cost>
+where
+    S: UniformCostSearchable + SolutionIdentifiable + Clone + Eq + Hash,
+    S::Cost: Add<S::Cost, Output = S::Cost> + Clone,
+{
+    fn receive<H>(
+        &mut self,
+        (state, parent, cumulative_cost): Transfer<S, S::Cost>,
+        shared: &Shared<S, S::Cost, H>,
+    ) where
+        H: Heuristic<S>,
+        S::Cost: From<H::Score>,
+    {
+        if self
+            .parents
+            .get(&state)
+            .is_some_and(|(_, best)| cumulative_cost >= *best)
+        {
+            shared.finish();
+            return;
+        }
+        let score = S::Cost::from(shared.heuristic.score(&state)) + cumulative_cost.clone();
+        self.parents
+            .insert(state.clone(), (parent, cumulative_cost.clone()));
+        self.fringe.push(OrderedSearchable {
+            state: (state, cumulative_cost),
+            score,
+        });
+    }
+
+    /// Explore the states owned by this worker until no states remain to be explored by any worker,
+    /// or until the budget runs out, then hand back the receiver to collect the states still in transit.
+    fn run<H>(
+        &mut self,
+        receiver: Receiver<Transfer<S, S::Cost>>,
+        shared: &Shared<S, S::Cost, H>,
+    ) -> Receiver<Transfer<S, S::Cost>>
+    where
+        H: Heuristic<S>,
+        S::Cost: From<H::Score>,
+    {
+        let _guard = AbandonOnPanic(shared);
+        loop {
+            if shared.abandoned.load(Ordering::SeqCst) || shared.interrupted.load(Ordering::SeqCst)
+            {
+                return receiver;
+            }
+            for transfer in receiver.try_iter() {
+                self.receive(transfer, shared);
+            }
+            let Some(OrderedSearchable {
+                state: (state, cumulative_cost),
+                score,
+            }) = self.fringe.pop()
+            else {
+                if shared.outstanding.load(Ordering::SeqCst) == 0 {
+                    return receiver;
+                }
+                if let Ok(transfer) = receiver.recv_timeout(Duration::from_millis(1)) {
+                    self.receive(transfer, shared);
+                }
+                continue;
+            };
+            // skip stale entries superseded by a cheaper path to the same state, and states that
+            // cannot lead to a solution cheaper than the best one found so far
+            if self.parents[&state].1 < cumulative_cost || shared.pruned(&score) {
+                shared.finish();
+                continue;
+            }
+            if state.is_solution() {
+                shared.offer(state, cumulative_cost);
+                shared.finish();
+                continue;
+            }
+            if !shared.expand() {
+                // leave the state to be expanded once the search is resumed
+                self.fringe.push(OrderedSearchable {
+                    state: (state, cumulative_cost),
+                    score,
+                });
+                shared.interrupted.store(true, Ordering::SeqCst);
+                return receiver;
+            }
+            for (next_state, traversal_cost) in state.next_states_with_costs() {
+                shared.send((
+                    next_state,
+                    Some(state.clone()),
+                    cumulative_cost.clone() + traversal_cost,
+                ));
+                shared.generated.fetch_add(1, Ordering::SeqCst);
+            }
+            shared.finish();
+        }
+    }
+}
+
+/// Hash-distributed A* based, solution-route yielding, prior state exploration culling search manager.
+///
+/// Partitions the search space between worker threads by the hash of each state; every worker keeps
+/// its own fringe and explored states, and sends the successors of the states it expands to the
+/// workers owning them. The search runs within a single call to the searcher, which blocks until
+/// every state that could lead to a cheaper solution has been expanded, then yields the single
+/// cheapest route found. The yielded route is cost-optimal given an admissible heuristic.
+///
+/// The workers stop early once the searcher's [`max_expansions`](crate::Searcher::max_expansions),
+/// [`deadline`](crate::Searcher::deadline) or [`cancellation`](crate::Searcher::cancellation) token
+/// is reached, leaving the search to be resumed by the next call, and the states they expand are
+/// counted in the searcher's [`SearchStats`](crate::SearchStats). The searcher's observer is only
+/// notified of the yielded solution, as the workers expand states on their own threads.
+///
+/// Solutions are identified through [`SolutionIdentifiable`] by the workers themselves, so goal
+/// predicates cannot be attached to searchers using this manager.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(
+        serialize = "S: serde::Serialize, S::Cost: serde::Serialize",
+        deserialize = "S: serde::Deserialize<'de> + Eq + Hash, S::Cost: serde::Deserialize<'de> + Ord, H: Default"
+    ))
+)]
+pub struct Manager<S, H = ScoreableHeuristic>
+where
+    S: UniformCostSearchable,
+    H: Heuristic<S>,
+{
+    initial_states: Vec<S>,
+    workers: Vec<Worker<S, S::Cost>>,
+    incumbent: Option<(S, S::Cost)>,
+    finished: bool,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    heuristic: H,
+
+    /// Number of worker threads to search with. Defaults to the available parallelism of the machine.
+    /// Changes take no effect once the search has started.
+    pub threads: usize,
+}
+
+impl<S, H> Manager<S, H>
+where
+    S: UniformCostSearchable + SolutionIdentifiable + Clone + Eq + Hash + Send + Sync,
+    S::Cost: Add<S::Cost, Output = S::Cost>
+        + Sub<S::Cost, Output = S::Cost>
+        + Zero
+        + Clone
+        + Send
+        + Sync
+        + From<H::Score>,
+    H: Heuristic<S> + Sync,
+{
+    fn new(initial_states: Vec<S>, heuristic: H) -> Self {
+        Self {
+            initial_states,
+            workers: Vec::new(),
+            incumbent: None,
+            finished: false,
+            heuristic,
+            threads: thread::available_parallelism().map_or(1, |threads| threads.get()),
+        }
+    }
+
+    /// Return the parent and cumulative cost of an explored state.
+    fn parent(&self, state: &S) -> Option<&(Option<S>, S::Cost)> {
+        self.workers[owner(state, self.workers.len())]
+            .parents
+            .get(state)
+    }
+}
+
+impl<S, H> ExplorationManager for Manager<S, H>
+where
+    S: UniformCostSearchable + SolutionIdentifiable + Clone + Eq + Hash + Send + Sync,
+    S::Cost: Add<S::Cost, Output = S::Cost>
+        + Sub<S::Cost, Output = S::Cost>
+        + Zero
+        + Clone
+        + Send
+        + Sync
+        + From<H::Score>,
+    H: Heuristic<S> + Sync,
+{
+    type State = S;
+    type YieldResult = CostRoute<S, S::Cost>;
+
+    type FringeItem = Incumbent<S, S::Cost>;
+
+    type CurrentStateContext = ();
+
+    type NextStatesIterItem = (S, S::Cost);
+
+    fn initialize(initial_states: Vec<S>) -> Self {
+        Self::new(initial_states, H::default())
+    }
+
+    fn pop_state(&mut self) -> Option<Self::FringeItem> {
+        self.pop_state_within(&mut Budget::default())
+    }
+
+    fn pop_state_within(&mut self, budget: &mut Budget) -> Option<Self::FringeItem> {
+        if self.finished {
+            return None;
+        }
+        if self.workers.is_empty() {
+            self.workers = (0..self.threads.max(1))
+                .map(|_| Worker {
+                    parents: HashMap::new(),
+                    fringe: ScoredFringe::new(),
+                })
+                .collect();
+        }
+        let (senders, receivers): (Vec<_>, Vec<_>) =
+            self.workers.iter().map(|_| mpsc::channel()).unzip();
+        let shared = Shared {
+            senders,
+            incumbent: RwLock::new(self.incumbent.take()),
+            heuristic: &self.heuristic,
+            budget,
+            outstanding: AtomicUsize::new(
+                self.workers.iter().map(|worker| worker.fringe.len()).sum(),
+            ),
+            expanded: AtomicUsize::new(0),
+            generated: AtomicUsize::new(0),
+            interrupted: AtomicBool::new(false),
+            abandoned: AtomicBool::new(false),
+        };
+        for state in self.initial_states.drain(..) {
+            shared.send((state, None, S::Cost::zero()));
+        }
+        let shared = &shared;
+        let receivers: Vec<_> = thread::scope(|scope| {
+            let handles: Vec<_> = self
+                .workers
+                .iter_mut()
+                .zip(receivers)
+                .map(|(worker, receiver)| scope.spawn(move || worker.run(receiver, shared)))
+                .collect();
+            handles
+                .into_iter()
+                .map(|handle| {
+                    handle
+                        .join()
+                        .unwrap_or_else(|panic| panic::resume_unwind(panic))
+                })
+                .collect()
+        });
+        // every worker has stopped, so the states still in transit can be collected safely
+        for (worker, receiver) in self.workers.iter_mut().zip(receivers) {
+            for transfer in receiver.try_iter() {
+                worker.receive(transfer, shared);
+            }
+        }
+        let interrupted = shared.interrupted.load(Ordering::SeqCst);
+        let expanded = shared.expanded.load(Ordering::SeqCst);
+        let generated = shared.generated.load(Ordering::SeqCst);
+        let incumbent = shared
+            .incumbent
+            .write()
+            .expect("Solutions are never offered while panicking")
+            .take();
+        budget.record(expanded, generated, interrupted);
+        if interrupted {
+            self.incumbent = incumbent;
+            return None;
+        }
+        self.finished = true;
+        let (state, cumulative_cost) = incumbent?;
+        Some(Incumbent {
+            state,
+            cumulative_cost,
+        })
+    }
+
+    fn prepare_result_from(
+        &self,
+        Incumbent {
+            state,
+            cumulative_cost: total_cost,
+        }: Self::FringeItem,
+    ) -> Self::YieldResult {
+        let mut states = VecDeque::from([state]);
+        let mut step_costs = VecDeque::new();
+        let mut cumulative_cost = total_cost.clone();
+        while let Some((Some(parent), _)) = self.parent(&states[0]) {
+            let (_, parent_cost) = self
+                .parent(parent)
+                .expect("Parent states are always explored");
+            step_costs.push_front(cumulative_cost - parent_cost.clone());
+            cumulative_cost = parent_cost.clone();
+            states.push_front(parent.clone());
+        }
+        CostRoute {
+            states: states.into(),
+            step_costs: step_costs.into(),
+            total_cost,
+        }
+    }
+
+    fn valid_state(&mut self, _item: &Self::FringeItem) -> bool {
+        false
+    }
+
+    fn place_state(&mut self, _item: Self::FringeItem) {}
+
+    fn register_current_state(&mut self, _item: &Self::FringeItem) -> Self::CurrentStateContext {}
+
+    fn prepare_state(
+        &self,
+        _context: &Self::CurrentStateContext,
+        (state, cumulative_cost): Self::NextStatesIterItem,
+    ) -> Self::FringeItem {
+        Incumbent {
+            state,
+            cumulative_cost,
+        }
+    }
+
+    fn next_states_iter(
+        current_state: &Self::FringeItem,
+    ) -> impl Iterator<Item = Self::NextStatesIterItem> {
+        current_state.as_ref().next_states_with_costs()
+    }
+
+    fn fringe_size(&self) -> usize {
+        let fringes: usize = self.workers.iter().map(|worker| worker.fringe.len()).sum();
+        self.initial_states.len() + fringes
+    }
+
+    fn explored_size(&self) -> usize {
+        self.workers.iter().map(|worker| worker.parents.len()).sum()
+    }
+}
+
+impl<S, C> HeuristicExplorationManager for Manager<S, FnHeuristic<S, C>>
+where
+    S: UniformCostSearchable + SolutionIdentifiable + Clone + Eq + Hash + Send + Sync,
+    S::Cost: Add<S::Cost, Output = S::Cost>
+        + Sub<S::Cost, Output = S::Cost>
+        + Zero
+        + Clone
+        + Send
+        + Sync
+        + From<C>,
+    C: Ord + Default,
+{
+    type Score = C;
+
+    fn initialize_with_heuristic(initial_states: Vec<S>, heuristic: FnHeuristic<S, C>) -> Self {
+        Self::new(initial_states, heuristic)
+    }
+
+    fn set_heuristic(&mut self, heuristic: FnHeuristic<S, C>) {
+        self.heuristic = heuristic;
+    }
+}
+
+#[test]
+fn test() {
+    use crate::*;
+
+    #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+    struct Pos(i32, i32);
+
+    impl Scoreable for Pos {
+        type Score = i32;
+
+        fn score(&self) -> Self::Score {
+            let &Pos(x, y) = self;
+            (x - 3).abs() + (y - 3).abs()
+        }
+    }
+
+    impl CostSearchable for Pos {
+        type Cost = i32;
+
+        fn next_states_with_costs(&self) -> impl Iterator<Item = (Self, Self::Cost)> {
+            let &Pos(x, y) = self;
+            [
+                (Pos(x - 1, y), 1),
+                (Pos(x, y - 1), 1),
+                (Pos(x + 1, y), if y == 0 { 1 } else { 3 }),
+                (Pos(x, y + 1), if x == 3 { 1 } else { 2 }),
+            ]
+            .into_iter()
+        }
+    }
+
+    impl SolutionIdentifiable for Pos {
+        fn is_solution(&self) -> bool {
+            let &Pos(x, y) = self;
+            x == 3 && y == 3
+        }
+    }
+
+    for threads in [1, 4] {
+        let mut searcher: Searcher<Manager<_>> = Searcher::new(Pos(0, 0));
+        searcher.manager.threads = threads;
+        assert_eq!(
+            searcher.next(),
+            Some(CostRoute {
+                states: vec![
+                    Pos(0, 0),
+                    Pos(1, 0),
+                    Pos(2, 0),
+                    Pos(3, 0),
+                    Pos(3, 1),
+                    Pos(3, 2),
+                    Pos(3, 3)
+                ],
+                step_costs: vec![1; 6],
+                total_cost: 6,
+            })
+        );
+        assert_eq!(searcher.next(), None);
+    }
+}
+
+#[test]
+fn test_limits() {
+    use crate::*;
+    use std::time::{Duration, Instant};
+
+    #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+    struct Pos(i32, i32);
+
+    impl UniformCostSearchable for Pos {
+        type Cost = i32;
+
+        fn next_states_with_costs(&self) -> impl Iterator<Item = (Self, Self::Cost)> {
+            let &Pos(x, y) = self;
+            [
+                (Pos(x - 1, y), 1),
+                (Pos(x, y - 1), 1),
+                (Pos(x + 1, y), 1),
+                (Pos(x, y + 1), 1),
+            ]
+            .into_iter()
+        }
+    }
+
+    impl SolutionIdentifiable for Pos {
+        fn is_solution(&self) -> bool {
+            let &Pos(x, y) = self;
+            x == 20 && y == 20
+        }
+    }
+
+    /// Unbounded search space without a solution, which is never exhausted.
+    #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+    struct Line(i64);
+
+    impl UniformCostSearchable for Line {
+        type Cost = i64;
+
+        fn next_states_with_costs(&self) -> impl Iterator<Item = (Self, Self::Cost)> {
+            [(Line(self.0 - 1), 1), (Line(self.0 + 1), 1)].into_iter()
+        }
+    }
+
+    impl SolutionIdentifiable for Line {
+        fn is_solution(&self) -> bool {
+            false
+        }
+    }
+
+    let distance = |&Pos(x, y): &Pos| (20 - x).abs() + (20 - y).abs();
+
+    let mut searcher: Searcher<Manager<_, FnHeuristic<_, _>>> =
+        Searcher::new_with_heuristic(Line(0), |_: &Line| 0);
+    searcher.manager.threads = 4;
+    let started = Instant::now();
+    searcher.deadline = Some(started + Duration::from_millis(20));
+    assert_eq!(
+        searcher.try_next(),
+        SearchOutcome::LimitReached(SearchLimit::Deadline)
+    );
+    assert!(started.elapsed() < Duration::from_secs(5));
+    assert!(searcher.stats.states_expanded > 0);
+
+    let token = CancellationToken::new();
+    searcher.deadline = None;
+    searcher.cancellation = Some(token.clone());
+    let cancel = thread::spawn(move || {
+        thread::sleep(Duration::from_millis(20));
+        token.cancel();
+    });
+    assert_eq!(searcher.try_next(), SearchOutcome::Cancelled);
+    cancel.join().unwrap();
+
+    for threads in [1, 4] {
+        let mut searcher: Searcher<Manager<_, FnHeuristic<_, _>>> =
+            Searcher::new_with_heuristic(Pos(0, 0), distance);
+        searcher.manager.threads = threads;
+        searcher.max_expansions = Some(10);
+        assert_eq!(
+            searcher.try_next(),
+            SearchOutcome::LimitReached(SearchLimit::Expansions)
+        );
+        assert_eq!(searcher.stats.states_expanded, 10);
+        assert!(searcher.stats.states_generated >= 40);
+
+        searcher.max_expansions = None;
+        let SearchOutcome::Solution(route) = searcher.try_next() else {
+            panic!("the search resumes until a solution is found");
+        };
+        assert_eq!(route.states.first(), Some(&Pos(0, 0)));
+        assert_eq!(route.states.last(), Some(&Pos(20, 20)));
+        assert_eq!(route.total_cost, 40);
+        assert!(searcher.stats.states_expanded > 10);
+        assert_eq!(searcher.try_next(), SearchOutcome::Exhausted);
+    }
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_save() {
+    use crate::*;
+
+    #[derive(Clone, Debug, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+    struct Pos(i32, i32);
+
+    impl Scoreable for Pos {
+        type Score = i32;
+
+        fn score(&self) -> Self::Score {
+            let &Pos(x, y) = self;
+            (x - 3).abs() + (y - 3).abs()
+        }
+    }
+
+    impl CostSearchable for Pos {
+        type Cost = i32;
+
+        fn next_states_with_costs(&self) -> impl Iterator<Item = (Self, Self::Cost)> {
+            let &Pos(x, y) = self;
+            [
+                (Pos(x - 1, y), 1),
+                (Pos(x, y - 1), 1),
+                (Pos(x + 1, y), if y == 0 { 1 } else { 3 }),
+                (Pos(x, y + 1), if x == 3 { 1 } else { 2 }),
+            ]
+            .into_iter()
+        }
+    }
+
+    impl SolutionIdentifiable for Pos {
+        fn is_solution(&self) -> bool {
+            let &Pos(x, y) = self;
+            x == 3 && y == 3
+        }
+    }
+
+    let uninterrupted = Searcher::<Manager<_>>::new(Pos(0, 0)).next();
+    assert!(uninterrupted.is_some());
+
+    let mut searcher: Searcher<Manager<_>> = Searcher::new(Pos(0, 0));
+    searcher.manager.threads = 4;
+    searcher.max_expansions = Some(5);
+    assert_eq!(
+        searcher.try_next(),
+        SearchOutcome::LimitReached(SearchLimit::Expansions)
+    );
+
+    let mut checkpoint = Vec::new();
+    searcher.save(&mut checkpoint).unwrap();
+    let mut resumed: Searcher<Manager<Pos>> = Searcher::load(checkpoint.as_slice()).unwrap();
+    assert_eq!(resumed.stats, searcher.stats);
+    resumed.max_expansions = None;
+    assert_eq!(resumed.manager.workers.len(), 4);
+    assert_eq!(resumed.next(), uninterrupted);
+}
@@ -1,82 +1,181 @@
 use std::{
-    collections::{BinaryHeap, HashSet},
+    collections::HashSet,
     hash::Hash,
-    ops::Add,
+    ops::{Add, Mul, Sub},
+    sync::Arc,
 };
 
-use num::Zero;
+use num::{One, Zero};
 
 use crate::{
-    prepare_result_from_state_parent_map, CostSearchable, ExplorationManager, OrderedSearchable,
-    Scoreable, StateParent, StateParentCumulativeCost,
+    prepare_cost_route_from_state_parent_map, CostRoute, ExplorationManager, FnHeuristic,
+    Heuristic, HeuristicExplorationManager, NodeItem, Nodes, OrderedSearchable, ScoreableHeuristic,
+    ScoredFringe, SearchLimit, StateParentCumulativeCost, UniformCostSearchable,
 };
 
 /// A* based, solution-route yielding, prior state exploration culling search manager.
-pub struct Manager<S>
+///
+/// Keeps track of the cheapest known cumulative cost of every explored state; states reached again
+/// through a cheaper path replace their previous fringe entries, so yielded routes are cost-optimal
+/// given an admissible heuristic.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(
+        serialize = "S: serde::Serialize, S::Cost: serde::Serialize",
+        deserialize = "S: serde::Deserialize<'de> + Eq + Hash, S::Cost: serde::Deserialize<'de>"
+    ))
+)]
+pub struct Manager<S, H = ScoreableHeuristic>
 where
-    S: Scoreable,
+    S: UniformCostSearchable,
+    H: Heuristic<S>,
 {
-    explored: HashSet<S>,
-    fringe: BinaryHeap<OrderedSearchable<StateParentCumulativeCost<S, S::Score>, S::Score>>,
-    parents: Vec<StateParent<S>>,
+    explored: Nodes<S, StateParentCumulativeCost<S, S::Cost>>,
+    closed: HashSet<usize>,
+    fringe: ScoredFringe<usize, S::Cost>,
+    cost_limited: bool,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    heuristic: H,
+
+    /// Toggle re-opening of already expanded states on. By default, a state is never expanded twice.
+    /// Enable this flag if your heuristic is admissible but inconsistent, to have states expanded
+    /// again whenever a cheaper path to them is found.
+    pub reopen_closed: bool,
+
+    /// Weight applied to the heuristic score of every state. Defaults to one, performing regular A* search.
+    /// Set this above one to perform weighted A* search, which explores towards solutions more
+    /// greedily; solutions found then cost at most `weight` times as much to reach as an optimal one.
+    /// Being a cost, the weight of integer costs is a whole number; use a fractional cost type such as
+    /// [`num::rational::Ratio`] for weights like 1.5.
+    pub weight: S::Cost,
+
+    /// Maximum estimated total cost of routes to explore. By default, no limit is applied.
+    /// States whose cumulative cost plus heuristic score exceeds this are discarded, and the search
+    /// stops with [`SearchLimit::Cost`] once no states within the limit remain.
+    pub max_cost: Option<S::Cost>,
 }
 
-impl<S> ExplorationManager for Manager<S>
+impl<S, H> Manager<S, H>
 where
-    S: CostSearchable + Clone + Eq + Hash,
-    S::Score: Add<S::Score, Output = S::Score> + Zero + Clone,
+    S: UniformCostSearchable + Clone + Eq + Hash,
+    S::Cost: Add<S::Cost, Output = S::Cost>
+        + Sub<S::Cost, Output = S::Cost>
+        + Mul<S::Cost, Output = S::Cost>
+        + Zero
+        + One
+        + Clone
+        + From<H::Score>,
+    H: Heuristic<S>,
+{
+    fn new(initial_states: Vec<S>, heuristic: H) -> Self {
+        let mut explored = Nodes::new();
+        let fringe = initial_states
+            .into_iter()
+            .filter_map(|state| {
+                let score = S::Cost::from(heuristic.score(&state));
+                explored
+                    .insert(StateParentCumulativeCost {
+                        state: Arc::new(state),
+                        parent: None,
+                        cumulative_cost: S::Cost::zero(),
+                    })
+                    .map(|index| OrderedSearchable {
+                        state: index,
+                        score,
+                    })
+            })
+            .collect();
+        Self {
+            explored,
+            closed: HashSet::new(),
+            fringe,
+            cost_limited: false,
+            heuristic,
+            reopen_closed: false,
+            weight: S::Cost::one(),
+            max_cost: None,
+        }
+    }
+}
+
+impl<S, H> ExplorationManager for Manager<S, H>
+where
+    S: UniformCostSearchable + Clone + Eq + Hash,
+    S::Cost: Add<S::Cost, Output = S::Cost>
+        + Sub<S::Cost, Output = S::Cost>
+        + Mul<S::Cost, Output = S::Cost>
+        + Zero
+        + One
+        + Clone
+        + From<H::Score>,
+    H: Heuristic<S>,
 {
     type State = S;
-    type YieldResult = Vec<S>;
+    type YieldResult = CostRoute<S, S::Cost>;
 
-    type FringeItem = StateParentCumulativeCost<S, S::Score>;
+    type FringeItem = NodeItem<StateParentCumulativeCost<S, S::Cost>>;
 
-    type CurrentStateContext = (usize, S::Score);
+    type CurrentStateContext = (usize, S::Cost);
 
-    type NextStatesIterItem = (S, S::Score);
+    type NextStatesIterItem = (S, S::Cost);
 
-    fn initialize(initial_state: S) -> Self {
-        let score = initial_state.score();
-        let initial_item = StateParentCumulativeCost {
-            state: initial_state.clone(),
-            parent: None,
-            cumulative_cost: S::Score::zero(),
-        };
-        Self {
-            explored: HashSet::from([initial_state.clone()]),
-            fringe: BinaryHeap::from([OrderedSearchable {
-                score,
-                state: initial_item.clone(),
-            }]),
-            parents: vec![initial_item.into()],
-        }
+    fn initialize(initial_states: Vec<S>) -> Self {
+        Self::new(initial_states, H::default())
     }
 
     fn pop_state(&mut self) -> Option<Self::FringeItem> {
-        self.fringe.pop().map(|s| s.state)
+        loop {
+            let index = self.fringe.pop()?.state;
+            let item = &self.explored[index];
+            // skip stale entries superseded by a cheaper path to the same state
+            if self.explored.index_of(&item.state) != Some(index) {
+                continue;
+            }
+            return Some(NodeItem {
+                item: item.clone(),
+                node: Some(index),
+            });
+        }
     }
 
-    fn prepare_result_from(&self, item: Self::FringeItem) -> Self::YieldResult {
-        prepare_result_from_state_parent_map(&self.parents, item.into())
+    fn prepare_result_from(&self, NodeItem { item, .. }: Self::FringeItem) -> Self::YieldResult {
+        prepare_cost_route_from_state_parent_map(&self.explored, item)
     }
 
-    fn valid_state(&mut self, item: &Self::FringeItem) -> bool {
-        if !self.explored.contains(&item.state) {
-            self.explored.insert(item.state.clone());
-            true
-        } else {
-            false
-        }
+    fn exceeds_limit(&mut self, NodeItem { item, .. }: &Self::FringeItem) -> bool {
+        let exceeds = self.max_cost.as_ref().is_some_and(|max_cost| {
+            S::Cost::from(self.heuristic.score(&item.state)) + item.cumulative_cost.clone()
+                > *max_cost
+        });
+        self.cost_limited |= exceeds;
+        exceeds
     }
 
-    fn place_state(&mut self, item: Self::FringeItem) {
-        let score = item.state.score() + item.cumulative_cost.clone();
-        self.fringe.push(OrderedSearchable { state: item, score })
+    fn valid_state(&mut self, NodeItem { item, .. }: &Self::FringeItem) -> bool {
+        self.explored.index_of(&item.state).is_none_or(|best| {
+            item.cumulative_cost < self.explored[best].cumulative_cost
+                && (self.reopen_closed || !self.closed.contains(&best))
+        })
     }
 
-    fn register_current_state(&mut self, item: &Self::FringeItem) -> Self::CurrentStateContext {
-        self.parents.push(item.clone().into());
-        (self.parents.len() - 1, item.cumulative_cost.clone())
+    fn place_state(&mut self, NodeItem { item, .. }: Self::FringeItem) {
+        let score = S::Cost::from(self.heuristic.score(&item.state)) * self.weight.clone()
+            + item.cumulative_cost.clone();
+        let index = self.explored.push(item);
+        self.fringe.push(OrderedSearchable {
+            state: index,
+            score,
+        })
+    }
+
+    fn register_current_state(
+        &mut self,
+        NodeItem { item, node }: &Self::FringeItem,
+    ) -> Self::CurrentStateContext {
+        let index = node.expect("Popped items always carry their node");
+        self.closed.insert(index);
+        (index, item.cumulative_cost.clone())
     }
 
     fn prepare_state(
@@ -84,15 +183,55 @@ where
         (parent, cumulative_cost): &Self::CurrentStateContext,
         (state, traversal_cost): Self::NextStatesIterItem,
     ) -> Self::FringeItem {
-        StateParentCumulativeCost {
-            state,
-            parent: Some(*parent),
-            cumulative_cost: cumulative_cost.clone() + traversal_cost,
+        NodeItem {
+            item: StateParentCumulativeCost {
+                state: Arc::new(state),
+                parent: Some(*parent),
+                cumulative_cost: cumulative_cost.clone() + traversal_cost,
+            },
+            node: None,
         }
     }
 
-    fn next_states_iter(current_state: &S) -> impl Iterator<Item = Self::NextStatesIterItem> {
-        current_state.next_states_with_costs()
+    fn next_states_iter(
+        current_state: &Self::FringeItem,
+    ) -> impl Iterator<Item = Self::NextStatesIterItem> {
+        current_state.as_ref().next_states_with_costs()
+    }
+
+    fn fringe_size(&self) -> usize {
+        self.fringe.len()
+    }
+
+    fn explored_size(&self) -> usize {
+        self.explored.states_len()
+    }
+
+    fn limit_reached(&self) -> Option<SearchLimit> {
+        self.cost_limited.then_some(SearchLimit::Cost)
+    }
+}
+
+impl<S, C> HeuristicExplorationManager for Manager<S, FnHeuristic<S, C>>
+where
+    S: UniformCostSearchable + Clone + Eq + Hash,
+    S::Cost: Add<S::Cost, Output = S::Cost>
+        + Sub<S::Cost, Output = S::Cost>
+        + Mul<S::Cost, Output = S::Cost>
+        + Zero
+        + One
+        + Clone
+        + From<C>,
+    C: Ord + Default,
+{
+    type Score = C;
+
+    fn initialize_with_heuristic(initial_states: Vec<S>, heuristic: FnHeuristic<S, C>) -> Self {
+        Self::new(initial_states, heuristic)
+    }
+
+    fn set_heuristic(&mut self, heuristic: FnHeuristic<S, C>) {
+        self.heuristic = heuristic;
     }
 }
 
@@ -104,7 +243,9 @@ fn test() {
     struct Pos(i32, i32);
 
     impl CostSearchable for Pos {
-        fn next_states_with_costs(&self) -> impl Iterator<Item = (Self, Self::Score)> {
+        type Cost = i32;
+
+        fn next_states_with_costs(&self) -> impl Iterator<Item = (Self, Self::Cost)> {
             let &Pos(x, y) = self;
             [Pos(x - 1, y), Pos(x, y - 1), Pos(x + 1, y), Pos(x, y + 1)]
                 .into_iter()
@@ -129,20 +270,199 @@ fn test() {
     }
 
     let mut searcher: Searcher<Manager<_>> = Searcher::new(Pos(0, 0));
+    let route = searcher.next().unwrap().states;
+    // any shortest route will do, ties between them are broken arbitrarily
+    assert_eq!(route.len(), 11);
+    assert_eq!(route.first(), Some(&Pos(0, 0)));
+    assert_eq!(route.last(), Some(&Pos(5, 5)));
+    assert!(route
+        .windows(2)
+        .all(|step| (step[0].0 - step[1].0).abs() + (step[0].1 - step[1].1).abs() == 1));
+}
+
+#[test]
+fn test_cheaper_path_found_later() {
+    use crate::*;
+
+    #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+    struct Node(u8);
+
+    impl CostSearchable for Node {
+        type Cost = u64;
+
+        fn next_states_with_costs(&self) -> impl Iterator<Item = (Self, Self::Cost)> {
+            let edges: &[(u8, u64)] = match self.0 {
+                0 => &[(1, 1), (2, 4)],
+                1 => &[(2, 1)],
+                2 => &[(3, 1)],
+                _ => &[],
+            };
+            edges.iter().map(|&(n, c)| (Node(n), c))
+        }
+    }
+
+    impl SolutionIdentifiable for Node {
+        fn is_solution(&self) -> bool {
+            self.0 == 3
+        }
+    }
+
+    impl Scoreable for Node {
+        type Score = u32;
+
+        fn score(&self) -> Self::Score {
+            0
+        }
+    }
+
+    let mut searcher: Searcher<Manager<_>> = Searcher::new(Node(0));
     assert_eq!(
         searcher.next(),
-        Some(vec![
-            Pos(0, 0),
-            Pos(1, 0),
-            Pos(1, 1),
-            Pos(2, 1),
-            Pos(2, 2),
-            Pos(2, 3),
-            Pos(3, 3),
-            Pos(3, 4),
-            Pos(4, 4),
-            Pos(5, 4),
-            Pos(5, 5)
-        ])
+        Some(CostRoute {
+            states: vec![Node(0), Node(1), Node(2), Node(3)],
+            step_costs: vec![1, 1, 1],
+            total_cost: 3,
+        })
+    );
+}
+
+#[test]
+fn test_multi_with_heuristic() {
+    use crate::*;
+
+    #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+    struct Pos(i32, i32);
+
+    impl UniformCostSearchable for Pos {
+        type Cost = i32;
+
+        fn next_states_with_costs(&self) -> impl Iterator<Item = (Self, Self::Cost)> {
+            let &Pos(x, y) = self;
+            [Pos(x - 1, y), Pos(x, y - 1), Pos(x + 1, y), Pos(x, y + 1)]
+                .into_iter()
+                .map(|s| (s, 1))
+        }
+    }
+
+    let goal = Pos(7, 8);
+    let mut searcher = Searcher::<Manager<_, FnHeuristic<_, _>>>::new_multi_with_heuristic(
+        [Pos(0, 0), Pos(9, 9)],
+        move |&Pos(x, y)| (x - goal.0).abs() + (y - goal.1).abs(),
+    )
+    .with_goal_predicate(move |pos: &Pos| *pos == goal);
+    let route = searcher.next().unwrap();
+    assert_eq!(route.states.first(), Some(&Pos(9, 9)));
+    assert_eq!(route.states.last(), Some(&Pos(7, 8)));
+    assert_eq!(route.total_cost, 3);
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_save() {
+    use crate::*;
+
+    #[derive(Clone, Debug, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+    struct Pos(i32, i32);
+
+    impl Scoreable for Pos {
+        type Score = i32;
+
+        fn score(&self) -> Self::Score {
+            let &Pos(x, y) = self;
+            (x - 3).abs() + (y - 3).abs()
+        }
+    }
+
+    impl CostSearchable for Pos {
+        type Cost = i32;
+
+        fn next_states_with_costs(&self) -> impl Iterator<Item = (Self, Self::Cost)> {
+            let &Pos(x, y) = self;
+            [
+                (Pos(x - 1, y), 1),
+                (Pos(x, y - 1), 1),
+                (Pos(x + 1, y), if y == 0 { 1 } else { 3 }),
+                (Pos(x, y + 1), if x == 3 { 1 } else { 2 }),
+            ]
+            .into_iter()
+        }
+    }
+
+    impl SolutionIdentifiable for Pos {
+        fn is_solution(&self) -> bool {
+            let &Pos(x, y) = self;
+            x == 3 && y == 3
+        }
+    }
+
+    let uninterrupted = Searcher::<Manager<_>>::new(Pos(0, 0)).next();
+    assert!(uninterrupted.is_some());
+
+    let mut searcher: Searcher<Manager<_>> = Searcher::new(Pos(0, 0));
+    searcher.max_expansions = Some(5);
+    assert_eq!(
+        searcher.try_next(),
+        SearchOutcome::LimitReached(SearchLimit::Expansions)
     );
+
+    let mut checkpoint = Vec::new();
+    searcher.save(&mut checkpoint).unwrap();
+    let mut resumed: Searcher<Manager<Pos>> = Searcher::load(checkpoint.as_slice()).unwrap();
+    assert_eq!(resumed.stats, searcher.stats);
+    resumed.max_expansions = None;
+    assert_eq!(resumed.next(), uninterrupted);
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_save_heuristic() {
+    use crate::*;
+
+    #[derive(Clone, Debug, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+    struct Pos(i32, i32);
+
+    impl UniformCostSearchable for Pos {
+        type Cost = i32;
+
+        fn next_states_with_costs(&self) -> impl Iterator<Item = (Self, Self::Cost)> {
+            let &Pos(x, y) = self;
+            [
+                (Pos(x - 1, y), 1),
+                (Pos(x, y - 1), 1),
+                (Pos(x + 1, y), if y == 0 { 1 } else { 3 }),
+                (Pos(x, y + 1), if x == 3 { 1 } else { 2 }),
+            ]
+            .into_iter()
+        }
+    }
+
+    let goal = Pos(3, -2);
+    let heuristic = move |&Pos(x, y): &Pos| (x - goal.0).abs() + (y - goal.1).abs();
+
+    let mut uninterrupted =
+        Searcher::<Manager<_, FnHeuristic<_, _>>>::new_with_heuristic(Pos(0, 0), heuristic)
+            .with_goal_predicate(|pos: &Pos| *pos == goal);
+    let route = uninterrupted.next();
+    assert!(route.is_some());
+
+    let mut searcher =
+        Searcher::<Manager<_, FnHeuristic<_, _>>>::new_with_heuristic(Pos(0, 0), heuristic)
+            .with_goal_predicate(|pos: &Pos| *pos == goal);
+    searcher.max_expansions = Some(3);
+    assert_eq!(
+        searcher.try_next(),
+        SearchOutcome::LimitReached(SearchLimit::Expansions)
+    );
+
+    let mut checkpoint = Vec::new();
+    searcher.save(&mut checkpoint).unwrap();
+    let mut resumed = Searcher::<Manager<Pos, FnHeuristic<_, _>>>::load_with_heuristic(
+        checkpoint.as_slice(),
+        heuristic,
+    )
+    .unwrap()
+    .with_goal_predicate(|pos: &Pos| *pos == goal);
+    resumed.max_expansions = None;
+    assert_eq!(resumed.next(), route);
+    assert_eq!(resumed.stats, uninterrupted.stats);
 }
@@ -1,49 +1,109 @@
-use std::{collections::BinaryHeap, ops::Add};
+use std::{
+    collections::BinaryHeap,
+    ops::{Add, Mul, Sub},
+    sync::Arc,
+};
 
-use num::Zero;
+use num::{One, Zero};
 
 use crate::{
-    prepare_result_from_state_parent_map, CostSearchable, ExplorationManager, OrderedSearchable,
-    Scoreable, StateParent, StateParentCumulativeCost,
+    prepare_cost_route_from_state_parent_map, CostRoute, ExplorationManager, FnHeuristic,
+    Heuristic, HeuristicExplorationManager, OrderedSearchable, ScoreableHeuristic, SearchLimit,
+    StateParentCumulativeCost, UniformCostSearchable,
 };
 
 /// A* based, solution-route yielding, unoptimized search manager.
-pub struct Manager<S>
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(
+        serialize = "S: serde::Serialize, S::Cost: serde::Serialize",
+        deserialize = "S: serde::Deserialize<'de>, S::Cost: serde::Deserialize<'de>"
+    ))
+)]
+pub struct Manager<S, H = ScoreableHeuristic>
 where
-    S: Scoreable,
+    S: UniformCostSearchable,
+    H: Heuristic<S>,
 {
-    fringe: BinaryHeap<OrderedSearchable<StateParentCumulativeCost<S, S::Score>, S::Score>>,
-    parents: Vec<StateParent<S>>,
+    #[allow(clippy::type_complexity)]
+    fringe: BinaryHeap<OrderedSearchable<StateParentCumulativeCost<S, S::Cost>, S::Cost>>,
+    cost_limited: bool,
+    parents: Vec<StateParentCumulativeCost<S, S::Cost>>,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    heuristic: H,
+
+    /// Weight applied to the heuristic score of every state. Defaults to one, performing regular A* search.
+    /// Set this above one to perform weighted A* search, which explores towards solutions more
+    /// greedily; solutions found then cost at most `weight` times as much to reach as an optimal one.
+    /// Being a cost, the weight of integer costs is a whole number; use a fractional cost type such as
+    /// [`num::rational::Ratio`] for weights like 1.5.
+    pub weight: S::Cost,
+
+    /// Maximum estimated total cost of routes to explore. By default, no limit is applied.
+    /// States whose cumulative cost plus heuristic score exceeds this are discarded, and the search
+    /// stops with [`SearchLimit::Cost`] once no states within the limit remain.
+    pub max_cost: Option<S::Cost>,
+}
+
+impl<S, H> Manager<S, H>
+where
+    S: UniformCostSearchable + Clone,
+    S::Cost: Add<S::Cost, Output = S::Cost>
+        + Sub<S::Cost, Output = S::Cost>
+        + Mul<S::Cost, Output = S::Cost>
+        + Zero
+        + One
+        + Clone
+        + From<H::Score>,
+    H: Heuristic<S>,
+{
+    fn new(initial_states: Vec<S>, heuristic: H) -> Self {
+        let fringe = initial_states
+            .into_iter()
+            .map(|state| OrderedSearchable {
+                score: S::Cost::from(heuristic.score(&state)),
+                state: StateParentCumulativeCost {
+                    state: Arc::new(state),
+                    parent: None,
+                    cumulative_cost: S::Cost::zero(),
+                },
+            })
+            .collect();
+        Self {
+            fringe,
+            parents: Vec::new(),
+            cost_limited: false,
+            heuristic,
+            weight: S::Cost::one(),
+            max_cost: None,
+        }
+    }
 }
 
-impl<S> ExplorationManager for Manager<S>
+impl<S, H> ExplorationManager for Manager<S, H>
 where
-    S: CostSearchable + Clone,
-    S::Score: Add<S::Score, Output = S::Score> + Zero + Clone,
+    S: UniformCostSearchable + Clone,
+    S::Cost: Add<S::Cost, Output = S::Cost>
+        + Sub<S::Cost, Output = S::Cost>
+        + Mul<S::Cost, Output = S::Cost>
+        + Zero
+        + One
+        + Clone
+        + From<H::Score>,
+    H: Heuristic<S>,
 {
     type State = S;
-    type YieldResult = Vec<S>;
+    type YieldResult = CostRoute<S, S::Cost>;
 
-    type FringeItem = StateParentCumulativeCost<S, S::Score>;
+    type FringeItem = StateParentCumulativeCost<S, S::Cost>;
 
-    type CurrentStateContext = (usize, S::Score);
+    type CurrentStateContext = (usize, S::Cost);
 
-    type NextStatesIterItem = (S, S::Score);
+    type NextStatesIterItem = (S, S::Cost);
 
-    fn initialize(initial_state: S) -> Self {
-        let score = initial_state.score();
-        let initial_item = StateParentCumulativeCost {
-            state: initial_state.clone(),
-            parent: None,
-            cumulative_cost: S::Score::zero(),
-        };
-        Self {
-            fringe: BinaryHeap::from([OrderedSearchable {
-                score,
-                state: initial_item.clone(),
-            }]),
-            parents: vec![initial_item.into()],
-        }
+    fn initialize(initial_states: Vec<S>) -> Self {
+        Self::new(initial_states, H::default())
     }
 
     fn pop_state(&mut self) -> Option<Self::FringeItem> {
@@ -51,7 +111,16 @@ where
     }
 
     fn prepare_result_from(&self, item: Self::FringeItem) -> Self::YieldResult {
-        prepare_result_from_state_parent_map(&self.parents, item.into())
+        prepare_cost_route_from_state_parent_map(&self.parents, item)
+    }
+
+    fn exceeds_limit(&mut self, item: &Self::FringeItem) -> bool {
+        let exceeds = self.max_cost.as_ref().is_some_and(|max_cost| {
+            S::Cost::from(self.heuristic.score(&item.state)) + item.cumulative_cost.clone()
+                > *max_cost
+        });
+        self.cost_limited |= exceeds;
+        exceeds
     }
 
     fn valid_state(&mut self, _item: &Self::FringeItem) -> bool {
@@ -59,12 +128,13 @@ where
     }
 
     fn place_state(&mut self, item: Self::FringeItem) {
-        let score = item.state.score() + item.cumulative_cost.clone();
+        let score = S::Cost::from(self.heuristic.score(&item.state)) * self.weight.clone()
+            + item.cumulative_cost.clone();
         self.fringe.push(OrderedSearchable { state: item, score })
     }
 
     fn register_current_state(&mut self, item: &Self::FringeItem) -> Self::CurrentStateContext {
-        self.parents.push(item.clone().into());
+        self.parents.push(item.clone());
         (self.parents.len() - 1, item.cumulative_cost.clone())
     }
 
@@ -74,14 +144,51 @@ where
         (state, traversal_cost): Self::NextStatesIterItem,
     ) -> Self::FringeItem {
         StateParentCumulativeCost {
-            state,
+            state: Arc::new(state),
             parent: Some(*parent),
             cumulative_cost: cumulative_cost.clone() + traversal_cost,
         }
     }
 
-    fn next_states_iter(current_state: &S) -> impl Iterator<Item = Self::NextStatesIterItem> {
-        current_state.next_states_with_costs()
+    fn next_states_iter(
+        current_state: &Self::FringeItem,
+    ) -> impl Iterator<Item = Self::NextStatesIterItem> {
+        current_state.as_ref().next_states_with_costs()
+    }
+
+    fn fringe_size(&self) -> usize {
+        self.fringe.len()
+    }
+
+    fn explored_size(&self) -> usize {
+        0
+    }
+
+    fn limit_reached(&self) -> Option<SearchLimit> {
+        self.cost_limited.then_some(SearchLimit::Cost)
+    }
+}
+
+impl<S, C> HeuristicExplorationManager for Manager<S, FnHeuristic<S, C>>
+where
+    S: UniformCostSearchable + Clone,
+    S::Cost: Add<S::Cost, Output = S::Cost>
+        + Sub<S::Cost, Output = S::Cost>
+        + Mul<S::Cost, Output = S::Cost>
+        + Zero
+        + One
+        + Clone
+        + From<C>,
+    C: Ord + Default,
+{
+    type Score = C;
+
+    fn initialize_with_heuristic(initial_states: Vec<S>, heuristic: FnHeuristic<S, C>) -> Self {
+        Self::new(initial_states, heuristic)
+    }
+
+    fn set_heuristic(&mut self, heuristic: FnHeuristic<S, C>) {
+        self.heuristic = heuristic;
     }
 }
 
@@ -93,7 +200,9 @@ fn test() {
     struct Pos(i32, i32);
 
     impl CostSearchable for Pos {
-        fn next_states_with_costs(&self) -> impl Iterator<Item = (Self, Self::Score)> {
+        type Cost = i32;
+
+        fn next_states_with_costs(&self) -> impl Iterator<Item = (Self, Self::Cost)> {
             let &Pos(x, y) = self;
             [Pos(x - 1, y), Pos(x, y - 1), Pos(x + 1, y), Pos(x, y + 1)]
                 .into_iter()
@@ -119,7 +228,7 @@ fn test() {
 
     let mut searcher: Searcher<Manager<_>> = Searcher::new(Pos(0, 0));
     assert_eq!(
-        searcher.next(),
+        searcher.next().map(|route| route.states),
         Some(vec![
             Pos(0, 0),
             Pos(1, 0),
@@ -0,0 +1,10 @@
+//! Module containing all bidirectional search managers.
+//!
+//! These managers search forwards from the initial state and backwards from a known goal state at the
+//! same time, yielding solution routes through the states where both searches meet. Create them with
+//! [`Searcher::new_with_goal`](crate::Searcher::new_with_goal). As the goal state determines where the
+//! backwards search starts, these managers do not support goal predicates attached with
+//! [`Searcher::with_goal_predicate`](crate::Searcher::with_goal_predicate).
+
+pub mod a_star;
+pub mod unguided;
@@ -0,0 +1,620 @@
+use std::{
+    hash::Hash,
+    ops::{Add, Sub},
+    sync::Arc,
+};
+
+use num::Zero;
+
+use crate::{
+    prepare_cost_route_from_bidirectional_state_parent_maps, CostRoute,
+    DirectedStateParentCumulativeCost, ExplorationManager, FnHeuristic, GoalExplorationManager,
+    GoalHeuristicExplorationManager, Heuristic, NodeItem, Nodes, OrderedSearchable,
+    ReverseScoreableHeuristic, ReverseUniformCostSearchable, ScoreableHeuristic, ScoredFringe,
+    StateParentCumulativeCost, UniformCostSearchable,
+};
+
+/// One direction of a bidirectional A* search.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(
+        serialize = "S: serde::Serialize, C: serde::Serialize",
+        deserialize = "S: serde::Deserialize<'de> + Eq + Hash, C: serde::Deserialize<'de> + Ord"
+    ))
+)]
+struct Side<S, C> {
+    explored: Nodes<S, StateParentCumulativeCost<S, C>>,
+    fringe: ScoredFringe<usize, C>,
+}
+
+impl<S, C> Side<S, C>
+where
+    S: Eq + Hash,
+    C: Ord,
+{
+    fn new() -> Self {
+        Self {
+            explored: Nodes::new(),
+            fringe: ScoredFringe::new(),
+        }
+    }
+
+    /// Discard fringe entries superseded by a cheaper path to the same state, and return the
+    /// priority of the first remaining entry.
+    fn min_priority(&mut self) -> Option<&C> {
+        while let Some(&OrderedSearchable { state: index, .. }) = self.fringe.peek() {
+            if self.explored.index_of(&self.explored[index].state) == Some(index) {
+                break;
+            }
+            self.fringe.pop();
+        }
+        self.fringe.peek().map(|o| &o.score)
+    }
+}
+
+/// Bidirectional, A* based, solution-route yielding, prior state exploration culling search manager.
+///
+/// Explores from both ends in the manner of the MM algorithm, expanding states in order of
+/// `max(f, 2g)` and stopping once no cheaper meeting point can exist. Yields a single cost-optimal
+/// route as a [`CostRoute`], given admissible heuristics in both directions.
+///
+/// Must be created with [`Searcher::new_with_goal`](crate::Searcher::new_with_goal), or with
+/// [`Searcher::new_with_goal_and_heuristics`](crate::Searcher::new_with_goal_and_heuristics) when
+/// both heuristics are [`FnHeuristic`]s; a manager created without a goal state has nothing to meet
+/// with, and yields no solutions.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(
+        serialize = "S: serde::Serialize, S::Cost: serde::Serialize",
+        deserialize = "S: serde::Deserialize<'de> + Eq + Hash, S::Cost: serde::Deserialize<'de>"
+    ))
+)]
+pub struct Manager<S, H = ScoreableHeuristic, R = ReverseScoreableHeuristic>
+where
+    S: UniformCostSearchable,
+    H: Heuristic<S>,
+    R: Heuristic<S>,
+{
+    forward: Side<S, S::Cost>,
+    backward: Side<S, S::Cost>,
+    best_meeting: Option<DirectedStateParentCumulativeCost<S, S::Cost>>,
+    finished: bool,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    heuristic: H,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    reverse_heuristic: R,
+}
+
+impl<S, H, R> Manager<S, H, R>
+where
+    S: ReverseUniformCostSearchable + Clone + Eq + Hash,
+    S::Cost: Add<S::Cost, Output = S::Cost>
+        + Sub<S::Cost, Output = S::Cost>
+        + Zero
+        + Clone
+        + From<H::Score>
+        + From<R::Score>,
+    H: Heuristic<S>,
+    R: Heuristic<S>,
+{
+    fn new(initial_states: Vec<S>, heuristic: H, reverse_heuristic: R) -> Self {
+        let mut manager = Self {
+            forward: Side::new(),
+            backward: Side::new(),
+            best_meeting: None,
+            finished: false,
+            heuristic,
+            reverse_heuristic,
+        };
+        for state in initial_states {
+            manager.seed(state, false);
+        }
+        manager
+    }
+
+    fn side_mut(&mut self, backward: bool) -> &mut Side<S, S::Cost> {
+        match backward {
+            true => &mut self.backward,
+            false => &mut self.forward,
+        }
+    }
+
+    /// Record the item as reaching its state at the cheapest known cost from its side and add it to
+    /// the fringe of its side, checking whether it meets the opposite side more cheaply than the best
+    /// meeting found so far.
+    fn place(&mut self, item: DirectedStateParentCumulativeCost<S, S::Cost>) {
+        let heuristic = match item.backward {
+            true => S::Cost::from(self.reverse_heuristic.score(&item.state)),
+            false => S::Cost::from(self.heuristic.score(&item.state)),
+        };
+        let estimate = item.cumulative_cost.clone() + heuristic;
+        let doubled_cost = item.cumulative_cost.clone() + item.cumulative_cost.clone();
+        let priority = estimate.max(doubled_cost);
+        let (side, opposite) = match item.backward {
+            true => (&mut self.backward, &self.forward),
+            false => (&mut self.forward, &self.backward),
+        };
+        if let Some(opposite_index) = opposite.explored.index_of(&item.state) {
+            let opposite_item = &opposite.explored[opposite_index];
+            let total_cost = item.cumulative_cost.clone() + opposite_item.cumulative_cost.clone();
+            if self
+                .best_meeting
+                .as_ref()
+                .is_none_or(|meeting| total_cost < meeting.cumulative_cost)
+            {
+                self.best_meeting = Some(DirectedStateParentCumulativeCost {
+                    state: item.state.clone(),
+                    parent: item.parent,
+                    backward: item.backward,
+                    cumulative_cost: total_cost,
+                    meeting: Some((opposite_item.parent, opposite_item.cumulative_cost.clone())),
+                });
+            }
+        }
+        let index = side.explored.push(StateParentCumulativeCost {
+            state: item.state,
+            parent: item.parent,
+            cumulative_cost: item.cumulative_cost,
+        });
+        side.fringe.push(OrderedSearchable {
+            state: index,
+            score: priority,
+        });
+    }
+
+    fn seed(&mut self, state: S, backward: bool) {
+        let item = DirectedStateParentCumulativeCost {
+            state: Arc::new(state),
+            parent: None,
+            backward,
+            cumulative_cost: S::Cost::zero(),
+            meeting: None,
+        };
+        self.place(item);
+    }
+}
+
+impl<S, H, R> ExplorationManager for Manager<S, H, R>
+where
+    S: ReverseUniformCostSearchable + Clone + Eq + Hash,
+    S::Cost: Add<S::Cost, Output = S::Cost>
+        + Sub<S::Cost, Output = S::Cost>
+        + Zero
+        + Clone
+        + From<H::Score>
+        + From<R::Score>,
+    H: Heuristic<S>,
+    R: Heuristic<S>,
+{
+    type State = S;
+    type YieldResult = CostRoute<S, S::Cost>;
+
+    type FringeItem = NodeItem<DirectedStateParentCumulativeCost<S, S::Cost>>;
+
+    type CurrentStateContext = (usize, bool, S::Cost);
+
+    type NextStatesIterItem = (S, S::Cost);
+
+    fn initialize(initial_states: Vec<S>) -> Self {
+        Self::new(initial_states, H::default(), R::default())
+    }
+
+    fn pop_state(&mut self) -> Option<Self::FringeItem> {
+        if self.finished {
+            return None;
+        }
+        let forward_priority = self.forward.min_priority().cloned();
+        let backward_priority = self.backward.min_priority().cloned();
+        let backward = match (forward_priority, backward_priority) {
+            (None, None) => {
+                self.finished = true;
+                return self.best_meeting.take().map(|meeting| NodeItem {
+                    item: meeting,
+                    node: None,
+                });
+            }
+            (Some(forward), Some(backward)) => backward < forward,
+            (forward, _) => forward.is_none(),
+        };
+        let side = self.side_mut(backward);
+        let lowest_priority = side.fringe.peek().map(|o| o.score.clone())?;
+        if self
+            .best_meeting
+            .as_ref()
+            .is_some_and(|meeting| meeting.cumulative_cost <= lowest_priority)
+        {
+            // no unexplored path can be cheaper than the best meeting found so far
+            self.finished = true;
+            return self.best_meeting.take().map(|meeting| NodeItem {
+                item: meeting,
+                node: None,
+            });
+        }
+        let side = self.side_mut(backward);
+        let index = side.fringe.pop()?.state;
+        let StateParentCumulativeCost {
+            state,
+            parent,
+            cumulative_cost,
+        } = side.explored[index].clone();
+        Some(NodeItem {
+            item: DirectedStateParentCumulativeCost {
+                state,
+                parent,
+                backward,
+                cumulative_cost,
+                meeting: None,
+            },
+            node: Some(index),
+        })
+    }
+
+    fn prepare_result_from(&self, NodeItem { item, .. }: Self::FringeItem) -> Self::YieldResult {
+        let (opposite_parent, opposite_cost) = item
+            .meeting
+            .expect("Only meeting states are identified as solutions");
+        let own_cost = item.cumulative_cost - opposite_cost.clone();
+        let ((forward_parent, forward_cost), (backward_parent, backward_cost)) = match item.backward
+        {
+            true => ((opposite_parent, opposite_cost), (item.parent, own_cost)),
+            false => ((item.parent, own_cost), (opposite_parent, opposite_cost)),
+        };
+        prepare_cost_route_from_bidirectional_state_parent_maps(
+            &self.forward.explored,
+            &self.backward.explored,
+            StateParentCumulativeCost {
+                state: item.state,
+                parent: forward_parent,
+                cumulative_cost: forward_cost,
+            },
+            backward_parent,
+            backward_cost,
+        )
+    }
+
+    fn valid_state(&mut self, NodeItem { item, .. }: &Self::FringeItem) -> bool {
+        let side = match item.backward {
+            true => &self.backward,
+            false => &self.forward,
+        };
+        side.explored
+            .index_of(&item.state)
+            .is_none_or(|best| item.cumulative_cost < side.explored[best].cumulative_cost)
+    }
+
+    fn place_state(&mut self, NodeItem { item, .. }: Self::FringeItem) {
+        self.place(item);
+    }
+
+    fn register_current_state(
+        &mut self,
+        NodeItem { item, node }: &Self::FringeItem,
+    ) -> Self::CurrentStateContext {
+        let index = node.expect("Popped items always carry their node");
+        (index, item.backward, item.cumulative_cost.clone())
+    }
+
+    fn prepare_state(
+        &self,
+        (parent, backward, cumulative_cost): &Self::CurrentStateContext,
+        (state, traversal_cost): Self::NextStatesIterItem,
+    ) -> Self::FringeItem {
+        NodeItem {
+            item: DirectedStateParentCumulativeCost {
+                state: Arc::new(state),
+                parent: Some(*parent),
+                backward: *backward,
+                cumulative_cost: cumulative_cost.clone() + traversal_cost,
+                meeting: None,
+            },
+            node: None,
+        }
+    }
+
+    fn next_states_iter(
+        current_state: &Self::FringeItem,
+    ) -> impl Iterator<Item = Self::NextStatesIterItem> {
+        let NodeItem { item, .. } = current_state;
+        let (next_states, previous_states) = match item.backward {
+            true => (None, Some(item.state.previous_states_with_costs())),
+            false => (Some(item.state.next_states_with_costs()), None),
+        };
+        next_states
+            .into_iter()
+            .flatten()
+            .chain(previous_states.into_iter().flatten())
+    }
+
+    fn fringe_size(&self) -> usize {
+        self.forward.fringe.len() + self.backward.fringe.len()
+    }
+
+    fn explored_size(&self) -> usize {
+        self.forward.explored.states_len() + self.backward.explored.states_len()
+    }
+}
+
+impl<S, H, R> GoalExplorationManager for Manager<S, H, R>
+where
+    S: ReverseUniformCostSearchable + Clone + Eq + Hash,
+    S::Cost: Add<S::Cost, Output = S::Cost>
+        + Sub<S::Cost, Output = S::Cost>
+        + Zero
+        + Clone
+        + From<H::Score>
+        + From<R::Score>,
+    H: Heuristic<S>,
+    R: Heuristic<S>,
+{
+    fn initialize_with_goal(initial_state: S, goal_state: S) -> Self {
+        let mut manager = Self::initialize(vec![initial_state]);
+        manager.seed(goal_state, true);
+        manager
+    }
+}
+
+impl<S, C> GoalHeuristicExplorationManager for Manager<S, FnHeuristic<S, C>, FnHeuristic<S, C>>
+where
+    S: ReverseUniformCostSearchable + Clone + Eq + Hash,
+    S::Cost:
+        Add<S::Cost, Output = S::Cost> + Sub<S::Cost, Output = S::Cost> + Zero + Clone + From<C>,
+    C: Ord + Default,
+{
+    type Score = C;
+
+    fn initialize_with_goal_and_heuristics(
+        initial_state: S,
+        goal_state: S,
+        heuristic: FnHeuristic<S, C>,
+        reverse_heuristic: FnHeuristic<S, C>,
+    ) -> Self {
+        let mut manager = Self::new(vec![initial_state], heuristic, reverse_heuristic);
+        manager.seed(goal_state, true);
+        manager
+    }
+
+    fn set_heuristics(
+        &mut self,
+        heuristic: FnHeuristic<S, C>,
+        reverse_heuristic: FnHeuristic<S, C>,
+    ) {
+        self.heuristic = heuristic;
+        self.reverse_heuristic = reverse_heuristic;
+    }
+}
+
+#[test]
+fn test() {
+    use crate::*;
+
+    #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+    struct Pos(i32, i32);
+
+    impl CostSearchable for Pos {
+        type Cost = i32;
+
+        fn next_states_with_costs(&self) -> impl Iterator<Item = (Self, Self::Cost)> {
+            let &Pos(x, y) = self;
+            [
+                (Pos(x + 1, y), if y == 0 { 1 } else { 3 }),
+                (Pos(x, y + 1), if x == 3 { 1 } else { 2 }),
+            ]
+            .into_iter()
+        }
+    }
+
+    impl ReverseCostSearchable for Pos {
+        fn previous_states_with_costs(&self) -> impl Iterator<Item = (Self, Self::Cost)> {
+            let &Pos(x, y) = self;
+            [
+                (Pos(x - 1, y), if y == 0 { 1 } else { 3 }),
+                (Pos(x, y - 1), if x == 3 { 1 } else { 2 }),
+            ]
+            .into_iter()
+        }
+
+        fn reverse_score(&self) -> Self::Score {
+            let &Pos(x, y) = self;
+            x.abs() + y.abs()
+        }
+    }
+
+    impl Scoreable for Pos {
+        type Score = i32;
+
+        fn score(&self) -> Self::Score {
+            let &Pos(x, y) = self;
+            (x - 3).abs() + (y - 3).abs()
+        }
+    }
+
+    let mut searcher: Searcher<Manager<_>> = Searcher::new_with_goal(Pos(0, 0), Pos(3, 3));
+    assert_eq!(
+        searcher
+            .next()
+            .map(|route| (route.states, route.total_cost)),
+        Some((
+            vec![
+                Pos(0, 0),
+                Pos(1, 0),
+                Pos(2, 0),
+                Pos(3, 0),
+                Pos(3, 1),
+                Pos(3, 2),
+                Pos(3, 3)
+            ],
+            6
+        ))
+    );
+    assert_eq!(searcher.next(), None);
+}
+
+#[test]
+fn test_goal_and_heuristics() {
+    use crate::*;
+
+    #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+    struct Pos(i32, i32);
+
+    impl UniformCostSearchable for Pos {
+        type Cost = i32;
+
+        fn next_states_with_costs(&self) -> impl Iterator<Item = (Self, Self::Cost)> {
+            let &Pos(x, y) = self;
+            [
+                (Pos(x - 1, y), 1),
+                (Pos(x, y - 1), 1),
+                (Pos(x + 1, y), 1),
+                (Pos(x, y + 1), 1),
+            ]
+            .into_iter()
+        }
+    }
+
+    impl ReverseUniformCostSearchable for Pos {
+        fn previous_states_with_costs(&self) -> impl Iterator<Item = (Self, Self::Cost)> {
+            self.next_states_with_costs()
+        }
+    }
+
+    let distance =
+        |Pos(x, y): &Pos, Pos(goal_x, goal_y): &Pos| (x - goal_x).abs() + (y - goal_y).abs();
+    let mut searcher: Searcher<Manager<_, FnHeuristic<_, _>, FnHeuristic<_, _>>> =
+        Searcher::new_with_goal_and_heuristics(
+            Pos(0, 0),
+            Pos(4, -3),
+            move |pos| distance(pos, &Pos(4, -3)),
+            move |pos| distance(pos, &Pos(0, 0)),
+        );
+    let route = searcher.next().unwrap();
+    assert_eq!(route.states.first(), Some(&Pos(0, 0)));
+    assert_eq!(route.states.last(), Some(&Pos(4, -3)));
+    assert_eq!(route.step_costs, vec![1; 7]);
+    assert_eq!(route.total_cost, 7);
+    assert_eq!(searcher.next(), None);
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_save() {
+    use crate::*;
+
+    #[derive(Clone, Debug, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+    struct Pos(i32, i32);
+
+    impl Scoreable for Pos {
+        type Score = i32;
+
+        fn score(&self) -> Self::Score {
+            let &Pos(x, y) = self;
+            (x - 3).abs() + (y - 3).abs()
+        }
+    }
+
+    impl CostSearchable for Pos {
+        type Cost = i32;
+
+        fn next_states_with_costs(&self) -> impl Iterator<Item = (Self, Self::Cost)> {
+            let &Pos(x, y) = self;
+            [
+                (Pos(x - 1, y), 1),
+                (Pos(x, y - 1), 1),
+                (Pos(x + 1, y), if y == 0 { 1 } else { 3 }),
+                (Pos(x, y + 1), if x == 3 { 1 } else { 2 }),
+            ]
+            .into_iter()
+        }
+    }
+
+    impl ReverseCostSearchable for Pos {
+        fn previous_states_with_costs(&self) -> impl Iterator<Item = (Self, Self::Cost)> {
+            let &Pos(x, y) = self;
+            [
+                (Pos(x + 1, y), 1),
+                (Pos(x, y + 1), 1),
+                (Pos(x - 1, y), if y == 0 { 1 } else { 3 }),
+                (Pos(x, y - 1), if x == 3 { 1 } else { 2 }),
+            ]
+            .into_iter()
+        }
+
+        fn reverse_score(&self) -> Self::Score {
+            let &Pos(x, y) = self;
+            x.abs() + y.abs()
+        }
+    }
+
+    let uninterrupted = Searcher::<Manager<_>>::new_with_goal(Pos(0, 0), Pos(3, 3)).next();
+    assert!(uninterrupted.is_some());
+
+    let mut searcher: Searcher<Manager<_>> = Searcher::new_with_goal(Pos(0, 0), Pos(3, 3));
+    searcher.max_expansions = Some(4);
+    assert_eq!(
+        searcher.try_next(),
+        SearchOutcome::LimitReached(SearchLimit::Expansions)
+    );
+
+    let mut checkpoint = Vec::new();
+    searcher.save(&mut checkpoint).unwrap();
+    let mut resumed: Searcher<Manager<Pos>> = Searcher::load(checkpoint.as_slice()).unwrap();
+    assert_eq!(resumed.stats, searcher.stats);
+    resumed.max_expansions = None;
+    assert_eq!(resumed.next(), uninterrupted);
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_save_heuristics() {
+    use crate::*;
+
+    #[derive(Clone, Debug, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+    struct Pos(i32, i32);
+
+    impl UniformCostSearchable for Pos {
+        type Cost = i32;
+
+        fn next_states_with_costs(&self) -> impl Iterator<Item = (Self, Self::Cost)> {
+            let &Pos(x, y) = self;
+            [
+                (Pos(x - 1, y), 1),
+                (Pos(x, y - 1), 1),
+                (Pos(x + 1, y), 1),
+                (Pos(x, y + 1), 1),
+            ]
+            .into_iter()
+        }
+    }
+
+    impl ReverseUniformCostSearchable for Pos {
+        fn previous_states_with_costs(&self) -> impl Iterator<Item = (Self, Self::Cost)> {
+            self.next_states_with_costs()
+        }
+    }
+
+    let heuristic = |&Pos(x, y): &Pos| (x - 4).abs() + (y + 3).abs();
+    let reverse_heuristic = |&Pos(x, y): &Pos| x.abs() + y.abs();
+
+    let mut uninterrupted: Searcher<Manager<_, FnHeuristic<_, _>, FnHeuristic<_, _>>> =
+        Searcher::new_with_goal_and_heuristics(Pos(0, 0), Pos(4, -3), heuristic, reverse_heuristic);
+    let route = uninterrupted.next();
+    assert!(route.is_some());
+
+    let mut searcher: Searcher<Manager<_, FnHeuristic<_, _>, FnHeuristic<_, _>>> =
+        Searcher::new_with_goal_and_heuristics(Pos(0, 0), Pos(4, -3), heuristic, reverse_heuristic);
+    searcher.max_expansions = Some(3);
+    assert_eq!(
+        searcher.try_next(),
+        SearchOutcome::LimitReached(SearchLimit::Expansions)
+    );
+
+    let mut checkpoint = Vec::new();
+    searcher.save(&mut checkpoint).unwrap();
+    let mut resumed: Searcher<Manager<Pos, FnHeuristic<_, _>, FnHeuristic<_, _>>> =
+        Searcher::load_with_heuristics(checkpoint.as_slice(), heuristic, reverse_heuristic)
+            .unwrap();
+    resumed.max_expansions = None;
+    assert_eq!(resumed.next(), route);
+    assert_eq!(resumed.stats, uninterrupted.stats);
+}
@@ -0,0 +1,282 @@
+use std::{collections::VecDeque, hash::Hash, sync::Arc};
+
+use crate::{
+    prepare_result_from_bidirectional_state_parent_maps, DirectedStateParent, ExplorationManager,
+    GoalExplorationManager, NodeItem, Nodes, ReverseSearchable, StateParent,
+};
+
+/// One direction of a bidirectional search.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(
+        serialize = "S: serde::Serialize",
+        deserialize = "S: serde::Deserialize<'de> + Eq + Hash"
+    ))
+)]
+struct Side<S> {
+    explored: Nodes<S, StateParent<S>>,
+    fringe: VecDeque<usize>,
+}
+
+impl<S> Side<S>
+where
+    S: Clone + Eq + Hash,
+{
+    fn new(initial_states: Vec<S>) -> Self {
+        let mut explored = Nodes::new();
+        let fringe = initial_states
+            .into_iter()
+            .filter_map(|state| {
+                explored.insert(StateParent {
+                    state: Arc::new(state),
+                    parent: None,
+                })
+            })
+            .collect();
+        Self { explored, fringe }
+    }
+}
+
+/// Bidirectional, unguided, solution-route yielding, prior state exploration culling search manager.
+///
+/// Alternates breadth-first expansion of whole layers between the forward and backward searches,
+/// always expanding the side with the smaller fringe. The first route yielded is a shortest route.
+///
+/// Must be created with [`Searcher::new_with_goal`](crate::Searcher::new_with_goal); a manager
+/// created without a goal state has nothing to meet with, and yields no solutions.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(
+        serialize = "S: serde::Serialize",
+        deserialize = "S: serde::Deserialize<'de> + Eq + Hash"
+    ))
+)]
+pub struct Manager<S> {
+    forward: Side<S>,
+    backward: Side<S>,
+    meetings: VecDeque<DirectedStateParent<S>>,
+    expanding_backward: bool,
+    layer_remaining: usize,
+}
+
+impl<S> Manager<S> {
+    fn side_mut(&mut self, backward: bool) -> &mut Side<S> {
+        match backward {
+            true => &mut self.backward,
+            false => &mut self.forward,
+        }
+    }
+}
+
+impl<S> ExplorationManager for Manager<S>
+where
+    S: ReverseSearchable + Clone + Eq + Hash,
+{
+    type State = S;
+    type YieldResult = Vec<S>;
+
+    type FringeItem = NodeItem<DirectedStateParent<S>>;
+
+    type CurrentStateContext = (usize, bool);
+
+    type NextStatesIterItem = S;
+
+    fn initialize(initial_states: Vec<S>) -> Self {
+        Self {
+            forward: Side::new(initial_states),
+            backward: Side::new(Vec::new()),
+            meetings: VecDeque::new(),
+            expanding_backward: false,
+            layer_remaining: 0,
+        }
+    }
+
+    fn pop_state(&mut self) -> Option<Self::FringeItem> {
+        if let Some(meeting) = self.meetings.pop_front() {
+            return Some(NodeItem {
+                item: meeting,
+                node: None,
+            });
+        }
+        if self.layer_remaining == 0 {
+            // start a new layer on the side with the smaller nonempty fringe
+            let (forward_len, backward_len) =
+                (self.forward.fringe.len(), self.backward.fringe.len());
+            self.expanding_backward = match (forward_len, backward_len) {
+                (0, 0) => return None,
+                (0, _) => true,
+                (_, 0) => false,
+                _ => backward_len < forward_len,
+            };
+            self.layer_remaining = match self.expanding_backward {
+                true => backward_len,
+                false => forward_len,
+            };
+        }
+        self.layer_remaining -= 1;
+        let backward = self.expanding_backward;
+        let side = self.side_mut(backward);
+        let index = side.fringe.pop_front()?;
+        let StateParent { state, parent } = side.explored[index].clone();
+        Some(NodeItem {
+            item: DirectedStateParent {
+                state,
+                parent,
+                backward,
+                meeting: None,
+            },
+            node: Some(index),
+        })
+    }
+
+    fn prepare_result_from(&self, NodeItem { item, .. }: Self::FringeItem) -> Self::YieldResult {
+        let opposite_parent = item
+            .meeting
+            .expect("Only meeting states are identified as solutions");
+        let (forward_parent, backward_parent) = match item.backward {
+            true => (opposite_parent, item.parent),
+            false => (item.parent, opposite_parent),
+        };
+        prepare_result_from_bidirectional_state_parent_maps(
+            &self.forward.explored,
+            &self.backward.explored,
+            item.state,
+            forward_parent,
+            backward_parent,
+        )
+    }
+
+    fn valid_state(&mut self, NodeItem { item, .. }: &Self::FringeItem) -> bool {
+        let (side, opposite) = match item.backward {
+            true => (&self.backward, &self.forward),
+            false => (&self.forward, &self.backward),
+        };
+        if side.explored.contains(&item.state) {
+            return false;
+        }
+        if let Some(opposite_index) = opposite.explored.index_of(&item.state) {
+            self.meetings.push_back(DirectedStateParent {
+                state: item.state.clone(),
+                parent: item.parent,
+                backward: item.backward,
+                meeting: Some(opposite.explored[opposite_index].parent),
+            });
+        }
+        true
+    }
+
+    fn place_state(&mut self, NodeItem { item, .. }: Self::FringeItem) {
+        let side = self.side_mut(item.backward);
+        side.fringe.extend(side.explored.insert(StateParent {
+            state: item.state,
+            parent: item.parent,
+        }));
+    }
+
+    fn register_current_state(
+        &mut self,
+        NodeItem { item, node }: &Self::FringeItem,
+    ) -> Self::CurrentStateContext {
+        let index = node.expect("Popped items always carry their node");
+        (index, item.backward)
+    }
+
+    fn prepare_state(
+        &self,
+        &(parent, backward): &Self::CurrentStateContext,
+        state: S,
+    ) -> Self::FringeItem {
+        NodeItem {
+            item: DirectedStateParent {
+                state: Arc::new(state),
+                parent: Some(parent),
+                backward,
+                meeting: None,
+            },
+            node: None,
+        }
+    }
+
+    fn next_states_iter(
+        current_state: &Self::FringeItem,
+    ) -> impl Iterator<Item = Self::NextStatesIterItem> {
+        let NodeItem { item, .. } = current_state;
+        let (next_states, previous_states) = match item.backward {
+            true => (None, Some(item.state.previous_states())),
+            false => (Some(item.state.next_states()), None),
+        };
+        next_states
+            .into_iter()
+            .flatten()
+            .chain(previous_states.into_iter().flatten())
+    }
+
+    fn fringe_size(&self) -> usize {
+        self.forward.fringe.len() + self.backward.fringe.len()
+    }
+
+    fn explored_size(&self) -> usize {
+        self.forward.explored.len() + self.backward.explored.len()
+    }
+}
+
+impl<S> GoalExplorationManager for Manager<S>
+where
+    S: ReverseSearchable + Clone + Eq + Hash,
+{
+    fn initialize_with_goal(initial_state: S, goal_state: S) -> Self {
+        let mut manager = Self::initialize(vec![initial_state.clone()]);
+        if initial_state == goal_state {
+            manager.meetings.push_back(DirectedStateParent {
+                state: Arc::new(initial_state),
+                parent: None,
+                backward: false,
+                meeting: Some(None),
+            });
+        }
+        manager.backward = Side::new(vec![goal_state]);
+        manager
+    }
+}
+
+#[test]
+fn test() {
+    use crate::*;
+
+    #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+    struct Pos(i32, i32);
+
+    impl Searchable for Pos {
+        fn next_states(&self) -> impl Iterator<Item = Self> {
+            let &Pos(x, y) = self;
+            [Pos(x + 1, y), Pos(x, y + 1)].into_iter()
+        }
+    }
+
+    impl ReverseSearchable for Pos {
+        fn previous_states(&self) -> impl Iterator<Item = Self> {
+            let &Pos(x, y) = self;
+            [Pos(x - 1, y), Pos(x, y - 1)].into_iter()
+        }
+    }
+
+    let mut searcher: Searcher<Manager<_>> = Searcher::new_with_goal(Pos(0, 0), Pos(5, 5));
+    assert_eq!(
+        searcher.next(),
+        Some(vec![
+            Pos(0, 0),
+            Pos(0, 1),
+            Pos(0, 2),
+            Pos(0, 3),
+            Pos(0, 4),
+            Pos(0, 5),
+            Pos(1, 5),
+            Pos(2, 5),
+            Pos(3, 5),
+            Pos(4, 5),
+            Pos(5, 5)
+        ])
+    );
+}
@@ -1,4 +1,5 @@
 //! Module containing all guided search managers.
 
+pub mod beam;
 pub mod no_route;
-pub mod route;
\ No newline at end of file
+pub mod route;
@@ -0,0 +1,34 @@
+//! Module containing all guided beam search managers.
+//!
+//! These managers explore the space one depth layer at a time, only keeping the best scoring states of
+//! each layer, up to the manager's `beam_width`. This bounds memory usage at the cost of completeness;
+//! solutions pruned from the beam will never be found.
+
+use crate::ScoredFringe;
+
+pub mod no_route;
+pub mod route;
+
+/// Default number of states kept per depth layer.
+pub const DEFAULT_BEAM_WIDTH: usize = 100;
+
+/// Take the best scoring candidates of the next layer accepted by `keep`, up to the beam width,
+/// discarding the rest. The returned layer is ordered such that popping from it yields the lowest
+/// scoring states first.
+fn narrow_beam<T, U, C>(
+    candidates: &mut ScoredFringe<T, C>,
+    beam_width: usize,
+    keep: impl FnMut(T) -> Option<U>,
+) -> Vec<U>
+where
+    C: Ord,
+{
+    let mut layer: Vec<U> = std::iter::from_fn(|| candidates.pop())
+        .map(|o| o.state)
+        .filter_map(keep)
+        .take(beam_width)
+        .collect();
+    candidates.clear();
+    layer.reverse();
+    layer
+}
@@ -0,0 +1,206 @@
+use std::{collections::HashSet, hash::Hash};
+
+use crate::{
+    search::guided::beam::{narrow_beam, DEFAULT_BEAM_WIDTH},
+    ExplorationManager, FnHeuristic, Heuristic, HeuristicExplorationManager, NoContext,
+    OrderedSearchable, ScoreableHeuristic, ScoredFringe, Searchable,
+};
+
+/// guided beam search, solution-only yielding, prior state exploration culling search manager.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(
+        serialize = "S: serde::Serialize, H::Score: serde::Serialize",
+        deserialize = "S: serde::Deserialize<'de> + Eq + Hash, H::Score: serde::Deserialize<'de>"
+    ))
+)]
+pub struct Manager<S, H = ScoreableHeuristic>
+where
+    H: Heuristic<S>,
+{
+    explored: HashSet<S>,
+    layer: Vec<S>,
+    candidates: ScoredFringe<S, H::Score>,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    heuristic: H,
+
+    /// Maximum number of states kept per depth layer. Defaults to [`DEFAULT_BEAM_WIDTH`].
+    pub beam_width: usize,
+}
+
+impl<S, H> Manager<S, H>
+where
+    S: Searchable + Clone + Eq + Hash,
+    H: Heuristic<S>,
+{
+    fn new(initial_states: Vec<S>, heuristic: H) -> Self {
+        let mut explored = HashSet::new();
+        let layer = initial_states
+            .into_iter()
+            .filter(|state| explored.insert(state.clone()))
+            .collect();
+        Self {
+            explored,
+            layer,
+            candidates: ScoredFringe::new(),
+            heuristic,
+            beam_width: DEFAULT_BEAM_WIDTH,
+        }
+    }
+}
+
+impl<S, H> ExplorationManager for Manager<S, H>
+where
+    S: Searchable + Clone + Eq + Hash,
+    H: Heuristic<S>,
+{
+    type State = S;
+    type YieldResult = S;
+
+    type FringeItem = NoContext<S>;
+
+    type CurrentStateContext = ();
+
+    type NextStatesIterItem = S;
+
+    fn initialize(initial_states: Vec<S>) -> Self {
+        Self::new(initial_states, H::default())
+    }
+
+    fn pop_state(&mut self) -> Option<Self::FringeItem> {
+        if self.layer.is_empty() {
+            self.layer = narrow_beam(&mut self.candidates, self.beam_width, |state| {
+                self.explored.insert(state.clone()).then_some(state)
+            });
+        }
+        self.layer.pop().map(NoContext)
+    }
+
+    fn prepare_result_from(&self, NoContext(state): Self::FringeItem) -> Self::YieldResult {
+        state
+    }
+
+    fn valid_state(&mut self, NoContext(state): &Self::FringeItem) -> bool {
+        // states are only explored once kept in the beam, as the memory used is meant to be bounded by it
+        !self.explored.contains(state)
+    }
+
+    fn place_state(&mut self, NoContext(state): Self::FringeItem) {
+        self.candidates.push(OrderedSearchable {
+            score: self.heuristic.score(&state),
+            state,
+        });
+    }
+
+    fn register_current_state(&mut self, _item: &Self::FringeItem) -> Self::CurrentStateContext {}
+
+    fn prepare_state(&self, _context: &Self::CurrentStateContext, state: S) -> Self::FringeItem {
+        NoContext(state)
+    }
+
+    fn next_states_iter(
+        current_state: &Self::FringeItem,
+    ) -> impl Iterator<Item = Self::NextStatesIterItem> {
+        current_state.as_ref().next_states()
+    }
+
+    fn fringe_size(&self) -> usize {
+        self.layer.len() + self.candidates.len()
+    }
+
+    fn explored_size(&self) -> usize {
+        self.explored.len()
+    }
+}
+
+impl<S, C> HeuristicExplorationManager for Manager<S, FnHeuristic<S, C>>
+where
+    S: Searchable + Clone + Eq + Hash,
+    C: Ord + Default,
+{
+    type Score = C;
+
+    fn initialize_with_heuristic(initial_states: Vec<S>, heuristic: FnHeuristic<S, C>) -> Self {
+        Self::new(initial_states, heuristic)
+    }
+
+    fn set_heuristic(&mut self, heuristic: FnHeuristic<S, C>) {
+        self.heuristic = heuristic;
+    }
+}
+
+#[test]
+fn test() {
+    use crate::*;
+
+    #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+    struct Pos(i32, i32);
+
+    impl Searchable for Pos {
+        fn next_states(&self) -> impl Iterator<Item = Self> {
+            let &Pos(x, y) = self;
+            [Pos(x - 1, y), Pos(x, y - 1), Pos(x + 1, y), Pos(x, y + 1)].into_iter()
+        }
+    }
+
+    impl SolutionIdentifiable for Pos {
+        fn is_solution(&self) -> bool {
+            let &Pos(x, y) = self;
+            x == 5 && y == 5
+        }
+    }
+
+    impl Scoreable for Pos {
+        type Score = i32;
+
+        fn score(&self) -> Self::Score {
+            let &Pos(x, y) = self;
+            (x - 5).abs() + (y - 5).abs()
+        }
+    }
+
+    let mut searcher: Searcher<Manager<_>> = Searcher::new(Pos(0, 0));
+    searcher.manager.beam_width = 2;
+    assert_eq!(searcher.next(), Some(Pos(5, 5)));
+}
+
+#[test]
+fn test_explored_bounded_by_beam() {
+    use crate::*;
+
+    #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+    struct Pos(i32, i32);
+
+    impl Searchable for Pos {
+        fn next_states(&self) -> impl Iterator<Item = Self> {
+            let &Pos(x, y) = self;
+            [Pos(x - 1, y), Pos(x, y - 1), Pos(x + 1, y), Pos(x, y + 1)].into_iter()
+        }
+    }
+
+    impl SolutionIdentifiable for Pos {
+        fn is_solution(&self) -> bool {
+            false
+        }
+    }
+
+    impl Scoreable for Pos {
+        type Score = i32;
+
+        fn score(&self) -> Self::Score {
+            let &Pos(x, y) = self;
+            -x.abs() - y.abs()
+        }
+    }
+
+    let mut searcher: Searcher<Manager<_>> = Searcher::new(Pos(0, 0));
+    searcher.manager.beam_width = 2;
+    searcher.max_expansions = Some(100);
+    assert_eq!(
+        searcher.try_next(),
+        SearchOutcome::LimitReached(SearchLimit::Expansions)
+    );
+    // the initial state and the two states kept in each of the fifty layers expanded
+    assert_eq!(searcher.stats.explored_size, 101);
+}
@@ -0,0 +1,4 @@
+//! Module containing all guided beam search, solution-route yielding search managers.
+
+pub mod hashable;
+pub mod unhashable;
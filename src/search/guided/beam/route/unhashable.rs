@@ -0,0 +1,187 @@
+use std::sync::Arc;
+
+use crate::{
+    prepare_result_from_state_parent_map,
+    search::guided::beam::{narrow_beam, DEFAULT_BEAM_WIDTH},
+    ExplorationManager, FnHeuristic, Heuristic, HeuristicExplorationManager, OrderedSearchable,
+    ScoreableHeuristic, ScoredFringe, Searchable, StateParent,
+};
+
+/// guided beam search, solution-route yielding, unoptimized search manager.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(
+        serialize = "S: serde::Serialize, H::Score: serde::Serialize",
+        deserialize = "S: serde::Deserialize<'de>, H::Score: serde::Deserialize<'de>"
+    ))
+)]
+pub struct Manager<S, H = ScoreableHeuristic>
+where
+    H: Heuristic<S>,
+{
+    layer: Vec<StateParent<S>>,
+    candidates: ScoredFringe<StateParent<S>, H::Score>,
+    parents: Vec<StateParent<S>>,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    heuristic: H,
+
+    /// Maximum number of states kept per depth layer. Defaults to [`DEFAULT_BEAM_WIDTH`].
+    pub beam_width: usize,
+}
+
+impl<S, H> Manager<S, H>
+where
+    S: Searchable + Clone,
+    H: Heuristic<S>,
+{
+    fn new(initial_states: Vec<S>, heuristic: H) -> Self {
+        let layer = initial_states
+            .into_iter()
+            .map(|state| StateParent {
+                state: Arc::new(state),
+                parent: None,
+            })
+            .collect();
+        Self {
+            layer,
+            candidates: ScoredFringe::new(),
+            parents: Vec::new(),
+            heuristic,
+            beam_width: DEFAULT_BEAM_WIDTH,
+        }
+    }
+}
+
+impl<S, H> ExplorationManager for Manager<S, H>
+where
+    S: Searchable + Clone,
+    H: Heuristic<S>,
+{
+    type State = S;
+    type YieldResult = Vec<S>;
+
+    type FringeItem = StateParent<S>;
+
+    type CurrentStateContext = usize;
+
+    type NextStatesIterItem = S;
+
+    fn initialize(initial_states: Vec<S>) -> Self {
+        Self::new(initial_states, H::default())
+    }
+
+    fn pop_state(&mut self) -> Option<Self::FringeItem> {
+        if self.layer.is_empty() {
+            self.layer = narrow_beam(&mut self.candidates, self.beam_width, Some);
+        }
+        self.layer.pop()
+    }
+
+    fn prepare_result_from(&self, item: Self::FringeItem) -> Self::YieldResult {
+        prepare_result_from_state_parent_map(&self.parents, item)
+    }
+
+    fn valid_state(&mut self, _item: &Self::FringeItem) -> bool {
+        true
+    }
+
+    fn place_state(&mut self, item: Self::FringeItem) {
+        self.candidates.push(OrderedSearchable {
+            score: self.heuristic.score(&item.state),
+            state: item,
+        });
+    }
+
+    fn register_current_state(&mut self, item: &Self::FringeItem) -> Self::CurrentStateContext {
+        self.parents.push(item.clone());
+        self.parents.len() - 1
+    }
+
+    fn prepare_state(&self, context: &Self::CurrentStateContext, state: S) -> Self::FringeItem {
+        StateParent {
+            state: Arc::new(state),
+            parent: Some(*context),
+        }
+    }
+
+    fn next_states_iter(
+        current_state: &Self::FringeItem,
+    ) -> impl Iterator<Item = Self::NextStatesIterItem> {
+        current_state.as_ref().next_states()
+    }
+
+    fn fringe_size(&self) -> usize {
+        self.layer.len() + self.candidates.len()
+    }
+
+    fn explored_size(&self) -> usize {
+        0
+    }
+}
+
+impl<S, C> HeuristicExplorationManager for Manager<S, FnHeuristic<S, C>>
+where
+    S: Searchable + Clone,
+    C: Ord + Default,
+{
+    type Score = C;
+
+    fn initialize_with_heuristic(initial_states: Vec<S>, heuristic: FnHeuristic<S, C>) -> Self {
+        Self::new(initial_states, heuristic)
+    }
+
+    fn set_heuristic(&mut self, heuristic: FnHeuristic<S, C>) {
+        self.heuristic = heuristic;
+    }
+}
+
+#[test]
+fn test() {
+    use crate::*;
+
+    #[derive(Clone, Debug, PartialEq)]
+    struct Pos(i32, i32);
+
+    impl Searchable for Pos {
+        fn next_states(&self) -> impl Iterator<Item = Self> {
+            let &Pos(x, y) = self;
+            [Pos(x - 1, y), Pos(x, y - 1), Pos(x + 1, y), Pos(x, y + 1)].into_iter()
+        }
+    }
+
+    impl SolutionIdentifiable for Pos {
+        fn is_solution(&self) -> bool {
+            let &Pos(x, y) = self;
+            x == 5 && y == 5
+        }
+    }
+
+    impl Scoreable for Pos {
+        type Score = i32;
+
+        fn score(&self) -> Self::Score {
+            let &Pos(x, y) = self;
+            (x - 5).abs() + (y - 5).abs()
+        }
+    }
+
+    let mut searcher: Searcher<Manager<_>> = Searcher::new(Pos(0, 0));
+    searcher.manager.beam_width = 2;
+    assert_eq!(
+        searcher.next(),
+        Some(vec![
+            Pos(0, 0),
+            Pos(1, 0),
+            Pos(2, 0),
+            Pos(3, 0),
+            Pos(4, 0),
+            Pos(5, 0),
+            Pos(5, 1),
+            Pos(5, 2),
+            Pos(5, 3),
+            Pos(5, 4),
+            Pos(5, 5)
+        ])
+    );
+}
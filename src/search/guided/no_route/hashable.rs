@@ -3,20 +3,57 @@ use std::{
     hash::Hash,
 };
 
-use crate::{ExplorationManager, NoContext, OrderedSearchable, Scoreable, Searchable};
+use crate::{
+    ExplorationManager, FnHeuristic, Heuristic, HeuristicExplorationManager, NoContext,
+    OrderedSearchable, ScoreableHeuristic, Searchable,
+};
 
 /// guided, solution-only yielding, prior state exploration culling search manager.
-pub struct Manager<S>
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(
+        serialize = "S: serde::Serialize, H::Score: serde::Serialize",
+        deserialize = "S: serde::Deserialize<'de> + Eq + Hash, H::Score: serde::Deserialize<'de>"
+    ))
+)]
+pub struct Manager<S, H = ScoreableHeuristic>
 where
-    S: Scoreable,
+    H: Heuristic<S>,
 {
     explored: HashSet<S>,
-    fringe: BinaryHeap<OrderedSearchable<S, S::Score>>,
+    fringe: BinaryHeap<OrderedSearchable<S, H::Score>>,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    heuristic: H,
 }
 
-impl<S> ExplorationManager for Manager<S>
+impl<S, H> Manager<S, H>
 where
-    S: Searchable + Scoreable + Clone + Hash + Eq,
+    S: Searchable + Clone + Hash + Eq,
+    H: Heuristic<S>,
+{
+    fn new(initial_states: Vec<S>, heuristic: H) -> Self {
+        let mut explored = HashSet::new();
+        let fringe = initial_states
+            .into_iter()
+            .filter(|state| explored.insert(state.clone()))
+            .map(|state| OrderedSearchable {
+                score: heuristic.score(&state),
+                state,
+            })
+            .collect();
+        Self {
+            explored,
+            fringe,
+            heuristic,
+        }
+    }
+}
+
+impl<S, H> ExplorationManager for Manager<S, H>
+where
+    S: Searchable + Clone + Hash + Eq,
+    H: Heuristic<S>,
 {
     type State = S;
     type YieldResult = S;
@@ -27,11 +64,8 @@ where
 
     type NextStatesIterItem = S;
 
-    fn initialize(initial_state: S) -> Self {
-        Self {
-            explored: HashSet::from([initial_state.clone()]),
-            fringe: BinaryHeap::from([initial_state.into()]),
-        }
+    fn initialize(initial_states: Vec<S>) -> Self {
+        Self::new(initial_states, H::default())
     }
 
     fn pop_state(&mut self) -> Option<Self::FringeItem> {
@@ -52,7 +86,10 @@ where
     }
 
     fn place_state(&mut self, NoContext(state): Self::FringeItem) {
-        self.fringe.push(state.into())
+        self.fringe.push(OrderedSearchable {
+            score: self.heuristic.score(&state),
+            state,
+        })
     }
 
     fn register_current_state(&mut self, _item: &Self::FringeItem) -> Self::CurrentStateContext {}
@@ -61,8 +98,34 @@ where
         NoContext(state)
     }
 
-    fn next_states_iter(current_state: &S) -> impl Iterator<Item = Self::NextStatesIterItem> {
-        current_state.next_states()
+    fn next_states_iter(
+        current_state: &Self::FringeItem,
+    ) -> impl Iterator<Item = Self::NextStatesIterItem> {
+        current_state.as_ref().next_states()
+    }
+
+    fn fringe_size(&self) -> usize {
+        self.fringe.len()
+    }
+
+    fn explored_size(&self) -> usize {
+        self.explored.len()
+    }
+}
+
+impl<S, C> HeuristicExplorationManager for Manager<S, FnHeuristic<S, C>>
+where
+    S: Searchable + Clone + Hash + Eq,
+    C: Ord + Default,
+{
+    type Score = C;
+
+    fn initialize_with_heuristic(initial_states: Vec<S>, heuristic: FnHeuristic<S, C>) -> Self {
+        Self::new(initial_states, heuristic)
+    }
+
+    fn set_heuristic(&mut self, heuristic: FnHeuristic<S, C>) {
+        self.heuristic = heuristic;
     }
 }
 
@@ -99,3 +162,53 @@ fn test() {
     let mut searcher: Searcher<Manager<_>> = Searcher::new(Pos(0, 0));
     assert_eq!(searcher.next(), Some(Pos(5, 5)));
 }
+
+#[test]
+fn test_step() {
+    use crate::*;
+
+    #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+    struct Node(u8);
+
+    impl Searchable for Node {
+        fn next_states(&self) -> impl Iterator<Item = Self> {
+            let &Node(n) = self;
+            [n.checked_sub(1), (n < 2).then_some(n + 1)]
+                .into_iter()
+                .flatten()
+                .map(Node)
+        }
+    }
+
+    impl SolutionIdentifiable for Node {
+        fn is_solution(&self) -> bool {
+            self.0 == 2
+        }
+    }
+
+    impl Scoreable for Node {
+        type Score = u8;
+
+        fn score(&self) -> Self::Score {
+            2 - self.0
+        }
+    }
+
+    let mut searcher: Searcher<Manager<_>> = Searcher::new(Node(0));
+    assert_eq!(
+        searcher.step(),
+        SearchStep::Expanded {
+            generated: 1,
+            placed: 1
+        }
+    );
+    assert_eq!(
+        searcher.step(),
+        SearchStep::Expanded {
+            generated: 2,
+            placed: 1
+        }
+    );
+    assert_eq!(searcher.step(), SearchStep::Solution(Node(2)));
+    assert_eq!(searcher.step(), SearchStep::FringeEmpty);
+}
@@ -1,18 +1,49 @@
 use std::collections::BinaryHeap;
 
-use crate::{ExplorationManager, NoContext, OrderedSearchable, Scoreable, Searchable};
+use crate::{
+    ExplorationManager, FnHeuristic, Heuristic, HeuristicExplorationManager, NoContext,
+    OrderedSearchable, ScoreableHeuristic, Searchable,
+};
 
 /// guided, solution-only yielding, unoptimized search space manager.
-pub struct Manager<S>
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(
+        serialize = "S: serde::Serialize, H::Score: serde::Serialize",
+        deserialize = "S: serde::Deserialize<'de>, H::Score: serde::Deserialize<'de>"
+    ))
+)]
+pub struct Manager<S, H = ScoreableHeuristic>
 where
-    S: Scoreable,
+    H: Heuristic<S>,
 {
-    fringe: BinaryHeap<OrderedSearchable<S, S::Score>>,
+    fringe: BinaryHeap<OrderedSearchable<S, H::Score>>,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    heuristic: H,
 }
 
-impl<S> ExplorationManager for Manager<S>
+impl<S, H> Manager<S, H>
 where
-    S: Searchable + Scoreable,
+    S: Searchable,
+    H: Heuristic<S>,
+{
+    fn new(initial_states: Vec<S>, heuristic: H) -> Self {
+        let fringe = initial_states
+            .into_iter()
+            .map(|state| OrderedSearchable {
+                score: heuristic.score(&state),
+                state,
+            })
+            .collect();
+        Self { fringe, heuristic }
+    }
+}
+
+impl<S, H> ExplorationManager for Manager<S, H>
+where
+    S: Searchable,
+    H: Heuristic<S>,
 {
     type State = S;
     type YieldResult = S;
@@ -23,10 +54,8 @@ where
 
     type NextStatesIterItem = S;
 
-    fn initialize(initial_state: S) -> Self {
-        Self {
-            fringe: BinaryHeap::from([initial_state.into()]),
-        }
+    fn initialize(initial_states: Vec<S>) -> Self {
+        Self::new(initial_states, H::default())
     }
 
     fn pop_state(&mut self) -> Option<NoContext<S>> {
@@ -42,7 +71,10 @@ where
     }
 
     fn place_state(&mut self, NoContext(state): Self::FringeItem) {
-        self.fringe.push(state.into())
+        self.fringe.push(OrderedSearchable {
+            score: self.heuristic.score(&state),
+            state,
+        })
     }
 
     fn register_current_state(&mut self, _item: &Self::FringeItem) -> Self::CurrentStateContext {}
@@ -51,8 +83,34 @@ where
         NoContext(state)
     }
 
-    fn next_states_iter(current_state: &S) -> impl Iterator<Item = Self::NextStatesIterItem> {
-        current_state.next_states()
+    fn next_states_iter(
+        current_state: &Self::FringeItem,
+    ) -> impl Iterator<Item = Self::NextStatesIterItem> {
+        current_state.as_ref().next_states()
+    }
+
+    fn fringe_size(&self) -> usize {
+        self.fringe.len()
+    }
+
+    fn explored_size(&self) -> usize {
+        0
+    }
+}
+
+impl<S, C> HeuristicExplorationManager for Manager<S, FnHeuristic<S, C>>
+where
+    S: Searchable,
+    C: Ord + Default,
+{
+    type Score = C;
+
+    fn initialize_with_heuristic(initial_states: Vec<S>, heuristic: FnHeuristic<S, C>) -> Self {
+        Self::new(initial_states, heuristic)
+    }
+
+    fn set_heuristic(&mut self, heuristic: FnHeuristic<S, C>) {
+        self.heuristic = heuristic;
     }
 }
 
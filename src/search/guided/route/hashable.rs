@@ -1,83 +1,146 @@
-use std::{
-    collections::{BinaryHeap, HashSet},
-    hash::Hash,
-};
+use std::{collections::BinaryHeap, hash::Hash, sync::Arc};
 
 use crate::{
-    prepare_result_from_state_parent_map, ExplorationManager, OrderedSearchable, Scoreable,
+    prepare_result_from_state_parent_map, ExplorationManager, FnHeuristic, Heuristic,
+    HeuristicExplorationManager, NodeItem, Nodes, OrderedSearchable, ScoreableHeuristic,
     Searchable, StateParent,
 };
 
 /// guided, solution-route yielding, prior state exploration culling search manager.
-pub struct Manager<S>
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(
+        serialize = "S: serde::Serialize, H::Score: serde::Serialize",
+        deserialize = "S: serde::Deserialize<'de> + Eq + Hash, H::Score: serde::Deserialize<'de>"
+    ))
+)]
+pub struct Manager<S, H = ScoreableHeuristic>
 where
-    S: Scoreable,
+    H: Heuristic<S>,
 {
-    explored: HashSet<S>,
-    fringe: BinaryHeap<OrderedSearchable<StateParent<S>, S::Score>>,
-    parents: Vec<StateParent<S>>,
+    explored: Nodes<S, StateParent<S>>,
+    fringe: BinaryHeap<OrderedSearchable<usize, H::Score>>,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    heuristic: H,
 }
 
-impl<S> ExplorationManager for Manager<S>
+impl<S, H> Manager<S, H>
 where
-    S: Searchable + Scoreable + Clone + Eq + Hash,
+    S: Searchable + Clone + Eq + Hash,
+    H: Heuristic<S>,
+{
+    fn new(initial_states: Vec<S>, heuristic: H) -> Self {
+        let mut explored = Nodes::new();
+        let fringe = initial_states
+            .into_iter()
+            .filter_map(|state| {
+                let score = heuristic.score(&state);
+                explored
+                    .insert(StateParent {
+                        state: Arc::new(state),
+                        parent: None,
+                    })
+                    .map(|index| OrderedSearchable {
+                        state: index,
+                        score,
+                    })
+            })
+            .collect();
+        Self {
+            explored,
+            fringe,
+            heuristic,
+        }
+    }
+}
+
+impl<S, H> ExplorationManager for Manager<S, H>
+where
+    S: Searchable + Clone + Eq + Hash,
+    H: Heuristic<S>,
 {
     type State = S;
     type YieldResult = Vec<S>;
 
-    type FringeItem = StateParent<S>;
+    type FringeItem = NodeItem<StateParent<S>>;
 
     type CurrentStateContext = usize;
 
     type NextStatesIterItem = S;
 
-    fn initialize(initial_state: S) -> Self {
-        let initial_pair = StateParent {
-            state: initial_state.clone(),
-            parent: None,
-        };
-        Self {
-            explored: HashSet::from([initial_state]),
-            fringe: BinaryHeap::from([initial_pair.clone().into()]),
-            parents: vec![initial_pair],
-        }
+    fn initialize(initial_states: Vec<S>) -> Self {
+        Self::new(initial_states, H::default())
     }
 
     fn pop_state(&mut self) -> Option<Self::FringeItem> {
-        self.fringe.pop().map(|o| o.state)
+        let index = self.fringe.pop()?.state;
+        Some(NodeItem {
+            item: self.explored[index].clone(),
+            node: Some(index),
+        })
     }
 
-    fn prepare_result_from(&self, item: Self::FringeItem) -> Self::YieldResult {
-        prepare_result_from_state_parent_map(&self.parents, item)
+    fn prepare_result_from(&self, NodeItem { item, .. }: Self::FringeItem) -> Self::YieldResult {
+        prepare_result_from_state_parent_map(&self.explored, item)
     }
 
-    fn valid_state(&mut self, StateParent { state, parent: _ }: &Self::FringeItem) -> bool {
-        if !self.explored.contains(state) {
-            self.explored.insert(state.clone());
-            true
-        } else {
-            false
-        }
+    fn valid_state(&mut self, NodeItem { item, .. }: &Self::FringeItem) -> bool {
+        !self.explored.contains(&item.state)
     }
 
-    fn place_state(&mut self, item: Self::FringeItem) {
-        self.fringe.push(item.into());
+    fn place_state(&mut self, NodeItem { item, .. }: Self::FringeItem) {
+        let score = self.heuristic.score(&item.state);
+        if let Some(index) = self.explored.insert(item) {
+            self.fringe.push(OrderedSearchable {
+                state: index,
+                score,
+            });
+        }
     }
 
     fn register_current_state(&mut self, item: &Self::FringeItem) -> Self::CurrentStateContext {
-        self.parents.push(item.clone());
-        self.parents.len() - 1
+        item.node.expect("Popped items always carry their node")
     }
 
     fn prepare_state(&self, context: &Self::CurrentStateContext, state: S) -> Self::FringeItem {
-        StateParent {
-            state,
-            parent: Some(*context),
+        NodeItem {
+            item: StateParent {
+                state: Arc::new(state),
+                parent: Some(*context),
+            },
+            node: None,
         }
     }
 
-    fn next_states_iter(current_state: &S) -> impl Iterator<Item = Self::NextStatesIterItem> {
-        current_state.next_states()
+    fn next_states_iter(
+        current_state: &Self::FringeItem,
+    ) -> impl Iterator<Item = Self::NextStatesIterItem> {
+        current_state.as_ref().next_states()
+    }
+
+    fn fringe_size(&self) -> usize {
+        self.fringe.len()
+    }
+
+    fn explored_size(&self) -> usize {
+        self.explored.len()
+    }
+}
+
+impl<S, C> HeuristicExplorationManager for Manager<S, FnHeuristic<S, C>>
+where
+    S: Searchable + Clone + Eq + Hash,
+    C: Ord + Default,
+{
+    type Score = C;
+
+    fn initialize_with_heuristic(initial_states: Vec<S>, heuristic: FnHeuristic<S, C>) -> Self {
+        Self::new(initial_states, heuristic)
+    }
+
+    fn set_heuristic(&mut self, heuristic: FnHeuristic<S, C>) {
+        self.heuristic = heuristic;
     }
 }
 
@@ -129,3 +192,30 @@ fn test() {
         ])
     );
 }
+
+#[test]
+fn test_goal_predicate() {
+    use crate::*;
+
+    #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+    struct Pos(i32, i32);
+
+    impl Searchable for Pos {
+        fn next_states(&self) -> impl Iterator<Item = Self> {
+            let &Pos(x, y) = self;
+            [Pos(x - 1, y), Pos(x, y - 1), Pos(x + 1, y), Pos(x, y + 1)].into_iter()
+        }
+    }
+
+    for goal in [Pos(-2, 1), Pos(3, -4)] {
+        let Pos(gx, gy) = goal;
+        let mut searcher = Searcher::<Manager<_, FnHeuristic<_, _>>>::new_with_heuristic(
+            Pos(0, 0),
+            move |&Pos(x, y)| (x - gx).abs() + (y - gy).abs(),
+        )
+        .with_goal_predicate(|pos: &Pos| *pos == goal);
+        let route = searcher.next().unwrap();
+        assert_eq!(route.len() as i32, gx.abs() + gy.abs() + 1);
+        assert_eq!(route.last(), Some(&goal));
+    }
+}
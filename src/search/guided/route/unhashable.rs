@@ -1,22 +1,57 @@
-use std::collections::BinaryHeap;
+use std::{collections::BinaryHeap, sync::Arc};
 
 use crate::{
-    prepare_result_from_state_parent_map, ExplorationManager, OrderedSearchable, Scoreable,
-    Searchable, StateParent,
+    prepare_result_from_state_parent_map, ExplorationManager, FnHeuristic, Heuristic,
+    HeuristicExplorationManager, OrderedSearchable, ScoreableHeuristic, Searchable, StateParent,
 };
 
 /// guided, solution-route yielding, unoptimized search manager.
-pub struct Manager<S>
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(
+        serialize = "S: serde::Serialize, H::Score: serde::Serialize",
+        deserialize = "S: serde::Deserialize<'de>, H::Score: serde::Deserialize<'de>"
+    ))
+)]
+pub struct Manager<S, H = ScoreableHeuristic>
 where
-    S: Scoreable,
+    H: Heuristic<S>,
 {
-    fringe: BinaryHeap<OrderedSearchable<StateParent<S>, S::Score>>,
+    fringe: BinaryHeap<OrderedSearchable<StateParent<S>, H::Score>>,
     parents: Vec<StateParent<S>>,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    heuristic: H,
 }
 
-impl<S> ExplorationManager for Manager<S>
+impl<S, H> Manager<S, H>
 where
-    S: Scoreable + Searchable + Clone,
+    S: Searchable + Clone,
+    H: Heuristic<S>,
+{
+    fn new(initial_states: Vec<S>, heuristic: H) -> Self {
+        let fringe = initial_states
+            .into_iter()
+            .map(|state| OrderedSearchable {
+                score: heuristic.score(&state),
+                state: StateParent {
+                    state: Arc::new(state),
+                    parent: None,
+                },
+            })
+            .collect();
+        Self {
+            fringe,
+            parents: Vec::new(),
+            heuristic,
+        }
+    }
+}
+
+impl<S, H> ExplorationManager for Manager<S, H>
+where
+    S: Searchable + Clone,
+    H: Heuristic<S>,
 {
     type State = S;
     type YieldResult = Vec<S>;
@@ -27,15 +62,8 @@ where
 
     type NextStatesIterItem = S;
 
-    fn initialize(initial_state: S) -> Self {
-        let initial_pair = StateParent {
-            state: initial_state.clone(),
-            parent: None,
-        };
-        Self {
-            fringe: BinaryHeap::from([initial_pair.clone().into()]),
-            parents: vec![initial_pair],
-        }
+    fn initialize(initial_states: Vec<S>) -> Self {
+        Self::new(initial_states, H::default())
     }
 
     fn pop_state(&mut self) -> Option<Self::FringeItem> {
@@ -51,7 +79,10 @@ where
     }
 
     fn place_state(&mut self, item: Self::FringeItem) {
-        self.fringe.push(item.into());
+        self.fringe.push(OrderedSearchable {
+            score: self.heuristic.score(&item.state),
+            state: item,
+        });
     }
 
     fn register_current_state(&mut self, item: &Self::FringeItem) -> Self::CurrentStateContext {
@@ -61,13 +92,39 @@ where
 
     fn prepare_state(&self, context: &Self::CurrentStateContext, state: S) -> Self::FringeItem {
         StateParent {
-            state,
+            state: Arc::new(state),
             parent: Some(*context),
         }
     }
 
-    fn next_states_iter(current_state: &S) -> impl Iterator<Item = Self::NextStatesIterItem> {
-        current_state.next_states()
+    fn next_states_iter(
+        current_state: &Self::FringeItem,
+    ) -> impl Iterator<Item = Self::NextStatesIterItem> {
+        current_state.as_ref().next_states()
+    }
+
+    fn fringe_size(&self) -> usize {
+        self.fringe.len()
+    }
+
+    fn explored_size(&self) -> usize {
+        0
+    }
+}
+
+impl<S, C> HeuristicExplorationManager for Manager<S, FnHeuristic<S, C>>
+where
+    S: Searchable + Clone,
+    C: Ord + Default,
+{
+    type Score = C;
+
+    fn initialize_with_heuristic(initial_states: Vec<S>, heuristic: FnHeuristic<S, C>) -> Self {
+        Self::new(initial_states, heuristic)
+    }
+
+    fn set_heuristic(&mut self, heuristic: FnHeuristic<S, C>) {
+        self.heuristic = heuristic;
     }
 }
 
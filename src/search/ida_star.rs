@@ -0,0 +1,7 @@
+//! Module containing all iterative-deepening A* based search managers.
+//!
+//! These managers only keep the current search path in memory, re-exploring the space with an increasing
+//! cost bound on every iteration, and cull cycles by checking new states against the current path.
+
+pub mod no_route;
+pub mod route;
@@ -0,0 +1,234 @@
+use std::{cell::Cell, ops::Add};
+
+use num::Zero;
+
+use crate::{
+    ExplorationManager, FnHeuristic, Heuristic, HeuristicExplorationManager, ScoreableHeuristic,
+    StateDepthCumulativeCost, UniformCostSearchable,
+};
+
+/// Iterative-deepening A* based, solution-route yielding, current path cycle culling search manager.
+///
+/// Yields every solution within the first cost bound that contains one, then stops.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(
+        serialize = "S: serde::Serialize, S::Cost: serde::Serialize",
+        deserialize = "S: serde::Deserialize<'de>, S::Cost: serde::Deserialize<'de>"
+    ))
+)]
+pub struct Manager<S, H = ScoreableHeuristic>
+where
+    S: UniformCostSearchable,
+    H: Heuristic<S>,
+{
+    initial_states: Vec<S>,
+    fringe: Vec<StateDepthCumulativeCost<S, S::Cost>>,
+    path: Vec<S>,
+    bound: S::Cost,
+    next_bound: Option<S::Cost>,
+    solution_found: Cell<bool>,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    heuristic: H,
+}
+
+impl<S, H> Manager<S, H>
+where
+    S: UniformCostSearchable + Clone + PartialEq,
+    S::Cost: Add<S::Cost, Output = S::Cost> + Zero + Clone + From<H::Score>,
+    H: Heuristic<S>,
+{
+    fn new(initial_states: Vec<S>, heuristic: H) -> Self {
+        let bound = initial_states
+            .iter()
+            .map(|state| S::Cost::from(heuristic.score(state)))
+            .min()
+            .unwrap_or_else(S::Cost::zero);
+        let mut manager = Self {
+            bound,
+            fringe: Vec::new(),
+            initial_states,
+            path: Vec::new(),
+            next_bound: None,
+            solution_found: Cell::new(false),
+            heuristic,
+        };
+        manager.seed();
+        manager
+    }
+
+    /// Place every initial state on the fringe to begin a new iteration.
+    fn seed(&mut self) {
+        self.path.clear();
+        self.fringe.extend(
+            self.initial_states
+                .iter()
+                .map(|state| StateDepthCumulativeCost {
+                    state: state.clone(),
+                    depth: 0,
+                    cumulative_cost: S::Cost::zero(),
+                }),
+        );
+    }
+}
+
+impl<S, H> ExplorationManager for Manager<S, H>
+where
+    S: UniformCostSearchable + Clone + PartialEq,
+    S::Cost: Add<S::Cost, Output = S::Cost> + Zero + Clone + From<H::Score>,
+    H: Heuristic<S>,
+{
+    type State = S;
+    type YieldResult = Vec<S>;
+
+    type FringeItem = StateDepthCumulativeCost<S, S::Cost>;
+
+    type CurrentStateContext = (usize, S::Cost);
+
+    type NextStatesIterItem = (S, S::Cost);
+
+    fn initialize(initial_states: Vec<S>) -> Self {
+        Self::new(initial_states, H::default())
+    }
+
+    fn pop_state(&mut self) -> Option<Self::FringeItem> {
+        loop {
+            let Some(item) = self.fringe.pop() else {
+                // iteration exhausted; restart with the smallest bound that was exceeded
+                if self.solution_found.get() {
+                    return None;
+                }
+                self.bound = self.next_bound.take()?;
+                self.seed();
+                continue;
+            };
+            let estimate =
+                S::Cost::from(self.heuristic.score(&item.state)) + item.cumulative_cost.clone();
+            if estimate > self.bound {
+                if self.next_bound.as_ref().is_none_or(|b| estimate < *b) {
+                    self.next_bound = Some(estimate);
+                }
+                continue;
+            }
+            self.path.truncate(item.depth);
+            return Some(item);
+        }
+    }
+
+    fn prepare_result_from(&self, item: Self::FringeItem) -> Self::YieldResult {
+        self.solution_found.set(true);
+        let mut result = self.path.clone();
+        result.push(item.state);
+        result
+    }
+
+    fn valid_state(&mut self, item: &Self::FringeItem) -> bool {
+        !self.path.contains(&item.state)
+    }
+
+    fn place_state(&mut self, item: Self::FringeItem) {
+        self.fringe.push(item);
+    }
+
+    fn register_current_state(&mut self, item: &Self::FringeItem) -> Self::CurrentStateContext {
+        self.path.push(item.state.clone());
+        (item.depth + 1, item.cumulative_cost.clone())
+    }
+
+    fn prepare_state(
+        &self,
+        (depth, cumulative_cost): &Self::CurrentStateContext,
+        (state, traversal_cost): Self::NextStatesIterItem,
+    ) -> Self::FringeItem {
+        StateDepthCumulativeCost {
+            state,
+            depth: *depth,
+            cumulative_cost: cumulative_cost.clone() + traversal_cost,
+        }
+    }
+
+    fn next_states_iter(
+        current_state: &Self::FringeItem,
+    ) -> impl Iterator<Item = Self::NextStatesIterItem> {
+        current_state.as_ref().next_states_with_costs()
+    }
+
+    fn fringe_size(&self) -> usize {
+        self.fringe.len()
+    }
+
+    fn explored_size(&self) -> usize {
+        self.path.len()
+    }
+}
+
+impl<S, C> HeuristicExplorationManager for Manager<S, FnHeuristic<S, C>>
+where
+    S: UniformCostSearchable + Clone + PartialEq,
+    S::Cost: Add<S::Cost, Output = S::Cost> + Zero + Clone + From<C>,
+    C: Ord + Default,
+{
+    type Score = C;
+
+    fn initialize_with_heuristic(initial_states: Vec<S>, heuristic: FnHeuristic<S, C>) -> Self {
+        Self::new(initial_states, heuristic)
+    }
+
+    fn set_heuristic(&mut self, heuristic: FnHeuristic<S, C>) {
+        self.heuristic = heuristic;
+    }
+}
+
+#[test]
+fn test() {
+    use crate::*;
+
+    #[derive(Clone, Debug, PartialEq)]
+    struct Pos(i32, i32);
+
+    impl CostSearchable for Pos {
+        type Cost = i32;
+
+        fn next_states_with_costs(&self) -> impl Iterator<Item = (Self, Self::Cost)> {
+            let &Pos(x, y) = self;
+            [Pos(x - 1, y), Pos(x, y - 1), Pos(x + 1, y), Pos(x, y + 1)]
+                .into_iter()
+                .map(|s| (s, 1))
+        }
+    }
+
+    impl SolutionIdentifiable for Pos {
+        fn is_solution(&self) -> bool {
+            let &Pos(x, y) = self;
+            x == 5 && y == 5
+        }
+    }
+
+    impl Scoreable for Pos {
+        type Score = i32;
+
+        fn score(&self) -> Self::Score {
+            let &Pos(x, y) = self;
+            (x - 5).abs() + (y - 5).abs()
+        }
+    }
+
+    let mut searcher: Searcher<Manager<_>> = Searcher::new(Pos(0, 0));
+    assert_eq!(
+        searcher.next(),
+        Some(vec![
+            Pos(0, 0),
+            Pos(0, 1),
+            Pos(0, 2),
+            Pos(0, 3),
+            Pos(0, 4),
+            Pos(0, 5),
+            Pos(1, 5),
+            Pos(2, 5),
+            Pos(3, 5),
+            Pos(4, 5),
+            Pos(5, 5)
+        ])
+    );
+}
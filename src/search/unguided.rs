@@ -1,4 +1,5 @@
 //! Module containing all unguided search managers.
 
+pub mod action_route;
 pub mod no_route;
-pub mod route;
\ No newline at end of file
+pub mod route;
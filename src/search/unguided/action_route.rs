@@ -0,0 +1,4 @@
+//! Module containing all unguided, solution action-route yielding search managers.
+
+pub mod hashable;
+pub mod unhashable;
@@ -0,0 +1,164 @@
+use std::{collections::VecDeque, hash::Hash, sync::Arc};
+
+use crate::{
+    prepare_action_route_from_state_parent_map, ActionRoute, ActionSearchable, ExplorationManager,
+    NodeItem, Nodes, StateParentAction,
+};
+
+/// unguided, solution action-route yielding, prior state exploration culling search manager.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(
+        serialize = "S: serde::Serialize, S::Action: serde::Serialize",
+        deserialize = "S: serde::Deserialize<'de> + Eq + Hash, S::Action: serde::Deserialize<'de>"
+    ))
+)]
+pub struct Manager<S>
+where
+    S: ActionSearchable,
+{
+    explored: Nodes<S, StateParentAction<S, S::Action>>,
+    fringe: VecDeque<usize>,
+
+    /// Toggle depth-first searching on. By default, breadth-first search is used.
+    /// Enable this flag to perform depth-first search instead.
+    pub depth_first: bool,
+}
+
+impl<S> ExplorationManager for Manager<S>
+where
+    S: ActionSearchable + Clone + Eq + Hash,
+    S::Action: Clone,
+{
+    type State = S;
+    type YieldResult = ActionRoute<S, S::Action>;
+
+    type FringeItem = NodeItem<StateParentAction<S, S::Action>>;
+
+    type CurrentStateContext = usize;
+
+    type NextStatesIterItem = (S::Action, S);
+
+    fn initialize(initial_states: Vec<S>) -> Self {
+        let mut explored = Nodes::new();
+        let fringe = initial_states
+            .into_iter()
+            .filter_map(|state| {
+                explored.insert(StateParentAction {
+                    state: Arc::new(state),
+                    parent: None,
+                    action: None,
+                })
+            })
+            .collect();
+        Self {
+            explored,
+            fringe,
+            depth_first: false,
+        }
+    }
+
+    fn pop_state(&mut self) -> Option<Self::FringeItem> {
+        let index = match self.depth_first {
+            true => self.fringe.pop_back(),
+            false => self.fringe.pop_front(),
+        }?;
+        Some(NodeItem {
+            item: self.explored[index].clone(),
+            node: Some(index),
+        })
+    }
+
+    fn prepare_result_from(&self, NodeItem { item, .. }: Self::FringeItem) -> Self::YieldResult {
+        prepare_action_route_from_state_parent_map(&self.explored, item)
+    }
+
+    fn valid_state(&mut self, NodeItem { item, .. }: &Self::FringeItem) -> bool {
+        !self.explored.contains(&item.state)
+    }
+
+    fn place_state(&mut self, NodeItem { item, .. }: Self::FringeItem) {
+        self.fringe.extend(self.explored.insert(item));
+    }
+
+    fn register_current_state(&mut self, item: &Self::FringeItem) -> Self::CurrentStateContext {
+        item.node.expect("Popped items always carry their node")
+    }
+
+    fn prepare_state(
+        &self,
+        context: &Self::CurrentStateContext,
+        (action, state): Self::NextStatesIterItem,
+    ) -> Self::FringeItem {
+        NodeItem {
+            item: StateParentAction {
+                state: Arc::new(state),
+                parent: Some(*context),
+                action: Some(action),
+            },
+            node: None,
+        }
+    }
+
+    fn next_states_iter(
+        current_state: &Self::FringeItem,
+    ) -> impl Iterator<Item = Self::NextStatesIterItem> {
+        current_state.as_ref().next_actions()
+    }
+
+    fn fringe_size(&self) -> usize {
+        self.fringe.len()
+    }
+
+    fn explored_size(&self) -> usize {
+        self.explored.len()
+    }
+}
+
+#[test]
+fn test() {
+    use crate::*;
+
+    #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+    struct Pos(i32, i32);
+
+    #[derive(Clone, Debug, PartialEq)]
+    enum Move {
+        Left,
+        Up,
+        Right,
+        Down,
+    }
+
+    impl ActionSearchable for Pos {
+        type Action = Move;
+
+        fn next_actions(&self) -> impl Iterator<Item = (Self::Action, Self)> {
+            let &Pos(x, y) = self;
+            [
+                (Move::Left, Pos(x - 1, y)),
+                (Move::Up, Pos(x, y - 1)),
+                (Move::Right, Pos(x + 1, y)),
+                (Move::Down, Pos(x, y + 1)),
+            ]
+            .into_iter()
+        }
+    }
+
+    impl SolutionIdentifiable for Pos {
+        fn is_solution(&self) -> bool {
+            let &Pos(x, y) = self;
+            x == 2 && y == 2
+        }
+    }
+
+    let mut searcher: Searcher<Manager<_>> = Searcher::new(Pos(0, 0));
+    assert_eq!(
+        searcher.next(),
+        Some(ActionRoute {
+            states: vec![Pos(0, 0), Pos(1, 0), Pos(2, 0), Pos(2, 1), Pos(2, 2)],
+            actions: vec![Move::Right, Move::Right, Move::Down, Move::Down],
+        })
+    );
+}
@@ -0,0 +1,155 @@
+use std::{collections::VecDeque, sync::Arc};
+
+use crate::{
+    prepare_action_route_from_state_parent_map, ActionRoute, ActionSearchable, ExplorationManager,
+    StateParentAction,
+};
+
+/// unguided, solution action-route yielding, unoptimized search manager.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(
+        serialize = "S: serde::Serialize, S::Action: serde::Serialize",
+        deserialize = "S: serde::Deserialize<'de>, S::Action: serde::Deserialize<'de>"
+    ))
+)]
+pub struct Manager<S>
+where
+    S: ActionSearchable,
+{
+    fringe: VecDeque<StateParentAction<S, S::Action>>,
+    parents: Vec<StateParentAction<S, S::Action>>,
+
+    /// Toggle depth-first searching on. By default, breadth-first search is used.
+    /// Enable this flag to perform depth-first search instead.
+    pub depth_first: bool,
+}
+
+impl<S> ExplorationManager for Manager<S>
+where
+    S: ActionSearchable + Clone,
+    S::Action: Clone,
+{
+    type State = S;
+    type YieldResult = ActionRoute<S, S::Action>;
+
+    type FringeItem = StateParentAction<S, S::Action>;
+
+    type CurrentStateContext = usize;
+
+    type NextStatesIterItem = (S::Action, S);
+
+    fn initialize(initial_states: Vec<S>) -> Self {
+        let fringe = initial_states
+            .into_iter()
+            .map(|state| StateParentAction {
+                state: Arc::new(state),
+                parent: None,
+                action: None,
+            })
+            .collect();
+        Self {
+            fringe,
+            parents: Vec::new(),
+            depth_first: false,
+        }
+    }
+
+    fn pop_state(&mut self) -> Option<Self::FringeItem> {
+        match self.depth_first {
+            true => self.fringe.pop_back(),
+            false => self.fringe.pop_front(),
+        }
+    }
+
+    fn prepare_result_from(&self, item: Self::FringeItem) -> Self::YieldResult {
+        prepare_action_route_from_state_parent_map(&self.parents, item)
+    }
+
+    fn valid_state(&mut self, _item: &Self::FringeItem) -> bool {
+        true
+    }
+
+    fn place_state(&mut self, item: Self::FringeItem) {
+        self.fringe.push_back(item);
+    }
+
+    fn register_current_state(&mut self, item: &Self::FringeItem) -> Self::CurrentStateContext {
+        self.parents.push(item.clone());
+        self.parents.len() - 1
+    }
+
+    fn prepare_state(
+        &self,
+        context: &Self::CurrentStateContext,
+        (action, state): Self::NextStatesIterItem,
+    ) -> Self::FringeItem {
+        StateParentAction {
+            state: Arc::new(state),
+            parent: Some(*context),
+            action: Some(action),
+        }
+    }
+
+    fn next_states_iter(
+        current_state: &Self::FringeItem,
+    ) -> impl Iterator<Item = Self::NextStatesIterItem> {
+        current_state.as_ref().next_actions()
+    }
+
+    fn fringe_size(&self) -> usize {
+        self.fringe.len()
+    }
+
+    fn explored_size(&self) -> usize {
+        0
+    }
+}
+
+#[test]
+fn test() {
+    use crate::*;
+
+    #[derive(Clone, Debug, PartialEq)]
+    struct Pos(i32, i32);
+
+    #[derive(Clone, Debug, PartialEq)]
+    enum Move {
+        Left,
+        Up,
+        Right,
+        Down,
+    }
+
+    impl ActionSearchable for Pos {
+        type Action = Move;
+
+        fn next_actions(&self) -> impl Iterator<Item = (Self::Action, Self)> {
+            let &Pos(x, y) = self;
+            [
+                (Move::Left, Pos(x - 1, y)),
+                (Move::Up, Pos(x, y - 1)),
+                (Move::Right, Pos(x + 1, y)),
+                (Move::Down, Pos(x, y + 1)),
+            ]
+            .into_iter()
+        }
+    }
+
+    impl SolutionIdentifiable for Pos {
+        fn is_solution(&self) -> bool {
+            let &Pos(x, y) = self;
+            x == 2 && y == 2
+        }
+    }
+
+    let mut searcher: Searcher<Manager<_>> = Searcher::new(Pos(0, 0));
+    assert_eq!(
+        searcher.next(),
+        Some(ActionRoute {
+            states: vec![Pos(0, 0), Pos(1, 0), Pos(2, 0), Pos(2, 1), Pos(2, 2)],
+            actions: vec![Move::Right, Move::Right, Move::Down, Move::Down],
+        })
+    );
+}
@@ -1,4 +1,10 @@
 //! Module containing all unguided, solution-only yielding search managers.
 
+pub mod depth_limited;
+#[cfg(feature = "external")]
+pub mod external;
 pub mod hashable;
+pub mod iterative_deepening;
+#[cfg(feature = "parallel")]
+pub mod parallel;
 pub mod unhashable;
@@ -0,0 +1,444 @@
+use std::{
+    cmp::Reverse,
+    collections::BinaryHeap,
+    env,
+    fs::{self, File},
+    io::{self, BufReader, BufWriter, Write},
+    marker::PhantomData,
+    mem,
+    path::PathBuf,
+    process,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+use serde::{de::DeserializeOwned, Serialize};
+
+use crate::{ExplorationManager, NoContext, Searchable};
+
+/// Number of spill directories created by this process, to name each of them uniquely.
+static DIRECTORIES: AtomicUsize = AtomicUsize::new(0);
+
+/// File of sorted states, free of duplicates.
+struct Run {
+    path: PathBuf,
+    len: usize,
+}
+
+/// Reads the states of a [`Run`] in order.
+struct RunReader<S> {
+    reader: BufReader<File>,
+    remaining: usize,
+    states: PhantomData<S>,
+}
+
+impl<S> RunReader<S>
+where
+    S: DeserializeOwned,
+{
+    fn open(run: &Run) -> io::Result<Self> {
+        Ok(Self {
+            reader: BufReader::new(File::open(&run.path)?),
+            remaining: run.len,
+            states: PhantomData,
+        })
+    }
+
+    fn next(&mut self) -> io::Result<Option<S>> {
+        if self.remaining == 0 {
+            return Ok(None);
+        }
+        self.remaining -= 1;
+        bincode::serde::decode_from_std_read(&mut self.reader, bincode::config::standard())
+            .map(Some)
+            .map_err(io::Error::other)
+    }
+}
+
+/// Writes states to a new [`Run`], in the order they are to be read back.
+struct RunWriter {
+    writer: BufWriter<File>,
+    run: Run,
+}
+
+impl RunWriter {
+    fn create(path: PathBuf) -> io::Result<Self> {
+        Ok(Self {
+            writer: BufWriter::new(File::create(&path)?),
+            run: Run { path, len: 0 },
+        })
+    }
+
+    fn push(&mut self, state: &impl Serialize) -> io::Result<()> {
+        bincode::serde::encode_into_std_write(state, &mut self.writer, bincode::config::standard())
+            .map_err(io::Error::other)?;
+        self.run.len += 1;
+        Ok(())
+    }
+
+    fn finish(mut self) -> io::Result<Run> {
+        self.writer.flush()?;
+        Ok(self.run)
+    }
+}
+
+/// External memory breadth-first, solution-only yielding, prior state exploration culling search manager.
+///
+/// Keeps the layer being expanded, the successors generated from it and every state explored so far in
+/// files on disk rather than in memory, for state spaces too large to fit in memory. Successors are
+/// buffered in memory up to [`Manager::buffer_capacity`], then sorted and spilled to disk as a run;
+/// once a layer has been expanded, its runs are merged into the next layer, culling any state already
+/// explored against the sorted file of explored states (delayed duplicate detection). Duplicates are
+/// therefore culled by the manager itself rather than by the searcher, so they are not counted in
+/// [`SearchStats::duplicates_culled`](crate::SearchStats::duplicates_culled).
+///
+/// The files are created in a new directory inside [`Manager::spill_directory`], which is removed once
+/// the manager is dropped. This manager cannot be saved with `Searcher::save`.
+///
+/// Should reading or writing the files fail, the search stops, [`Searcher::try_next`] reports
+/// [`SearchOutcome::Failed`], and the error is kept in [`Manager::error`].
+///
+/// [`Searcher::try_next`]: crate::Searcher::try_next
+/// [`SearchOutcome::Failed`]: crate::SearchOutcome::Failed
+pub struct Manager<S> {
+    directory: Option<PathBuf>,
+    files_created: usize,
+    layer: Option<(Run, RunReader<S>)>,
+    explored: Option<Run>,
+    runs: Vec<Run>,
+    buffer: Vec<S>,
+    error: Option<io::Error>,
+
+    /// Directory in which to create the files of the search. Defaults to the temporary directory of the
+    /// system. Changes take no effect once the first layer of the search has been written.
+    pub spill_directory: PathBuf,
+
+    /// Number of successors to hold in memory before sorting and spilling them to disk. Defaults to
+    /// one million.
+    pub buffer_capacity: usize,
+}
+
+impl<S> Manager<S> {
+    /// Return the error that stopped the search, if reading or writing its files failed.
+    pub fn error(&self) -> Option<&io::Error> {
+        self.error.as_ref()
+    }
+}
+
+impl<S> Manager<S>
+where
+    S: Ord + Serialize + DeserializeOwned,
+{
+    fn create_run(&mut self) -> io::Result<RunWriter> {
+        let directory = match self.directory.take() {
+            Some(directory) => directory,
+            None => {
+                let directory = self.spill_directory.join(format!(
+                    "space-search-{}-{}",
+                    process::id(),
+                    DIRECTORIES.fetch_add(1, Ordering::Relaxed)
+                ));
+                fs::create_dir_all(&directory)?;
+                directory
+            }
+        };
+        let path = directory.join(self.files_created.to_string());
+        self.directory = Some(directory);
+        self.files_created += 1;
+        RunWriter::create(path)
+    }
+
+    /// Sort the buffered successors and write them to disk as a new run.
+    fn spill(&mut self) -> io::Result<()> {
+        if self.buffer.is_empty() {
+            return Ok(());
+        }
+        let mut states = mem::take(&mut self.buffer);
+        states.sort_unstable();
+        states.dedup();
+        let mut run = self.create_run()?;
+        for state in &states {
+            run.push(state)?;
+        }
+        self.runs.push(run.finish()?);
+        states.clear();
+        self.buffer = states;
+        Ok(())
+    }
+
+    /// Merge the runs of successors of the expanded layer into the next layer, culling the states
+    /// already explored, and add the states of the next layer to the explored states.
+    fn advance(&mut self) -> io::Result<()> {
+        self.spill()?;
+        if let Some((layer, _)) = self.layer.take() {
+            fs::remove_file(layer.path)?;
+        }
+
+        let runs = mem::take(&mut self.runs);
+        let mut readers = runs
+            .iter()
+            .map(RunReader::open)
+            .collect::<io::Result<Vec<_>>>()?;
+        let mut heads = BinaryHeap::new();
+        for (index, reader) in readers.iter_mut().enumerate() {
+            if let Some(state) = reader.next()? {
+                heads.push(Reverse((state, index)));
+            }
+        }
+
+        let explored = self.explored.take();
+        let mut explored_reader = explored.as_ref().map(RunReader::open).transpose()?;
+        let next_explored = |reader: &mut Option<RunReader<S>>| match reader {
+            Some(reader) => reader.next(),
+            None => Ok(None),
+        };
+        let mut explored_head = next_explored(&mut explored_reader)?;
+
+        let mut layer = self.create_run()?;
+        let mut merged_explored = self.create_run()?;
+        let mut previous = None;
+        while let Some(Reverse((state, index))) = heads.pop() {
+            if let Some(next) = readers[index].next()? {
+                heads.push(Reverse((next, index)));
+            }
+            // each run is free of duplicates, but runs may share states with one another
+            if previous.as_ref() == Some(&state) {
+                continue;
+            }
+            while let Some(explored_state) = explored_head.take_if(|explored| *explored < state) {
+                merged_explored.push(&explored_state)?;
+                explored_head = next_explored(&mut explored_reader)?;
+            }
+            if explored_head.as_ref() != Some(&state) {
+                layer.push(&state)?;
+                merged_explored.push(&state)?;
+            }
+            previous = Some(state);
+        }
+        while let Some(explored_state) = explored_head {
+            merged_explored.push(&explored_state)?;
+            explored_head = next_explored(&mut explored_reader)?;
+        }
+
+        drop(readers);
+        drop(explored_reader);
+        for run in runs.into_iter().chain(explored) {
+            fs::remove_file(run.path)?;
+        }
+        self.explored = Some(merged_explored.finish()?);
+        let layer = layer.finish()?;
+        let reader = RunReader::open(&layer)?;
+        self.layer = Some((layer, reader));
+        Ok(())
+    }
+
+    fn pop(&mut self) -> io::Result<Option<S>> {
+        if let Some((_, reader)) = &mut self.layer {
+            if let Some(state) = reader.next()? {
+                return Ok(Some(state));
+            }
+        }
+        if self.buffer.is_empty() && self.runs.is_empty() {
+            return Ok(None);
+        }
+        self.advance()?;
+        self.pop()
+    }
+}
+
+impl<S> ExplorationManager for Manager<S>
+where
+    S: Searchable + Ord + Serialize + DeserializeOwned,
+{
+    type State = S;
+    type YieldResult = S;
+
+    type FringeItem = NoContext<S>;
+
+    type CurrentStateContext = ();
+
+    type NextStatesIterItem = S;
+
+    fn initialize(initial_states: Vec<S>) -> Self {
+        Self {
+            directory: None,
+            files_created: 0,
+            layer: None,
+            explored: None,
+            runs: Vec::new(),
+            buffer: initial_states,
+            error: None,
+            spill_directory: env::temp_dir(),
+            buffer_capacity: 1_000_000,
+        }
+    }
+
+    fn pop_state(&mut self) -> Option<Self::FringeItem> {
+        if self.error.is_some() {
+            return None;
+        }
+        self.pop()
+            .unwrap_or_else(|error| {
+                self.error = Some(error);
+                None
+            })
+            .map(NoContext)
+    }
+
+    fn prepare_result_from(&self, NoContext(state): Self::FringeItem) -> Self::YieldResult {
+        state
+    }
+
+    fn valid_state(&mut self, _item: &Self::FringeItem) -> bool {
+        // duplicates are culled once the whole layer has been generated
+        true
+    }
+
+    fn place_state(&mut self, NoContext(state): Self::FringeItem) {
+        self.buffer.push(state);
+        if self.error.is_none() && self.buffer.len() >= self.buffer_capacity {
+            self.error = self.spill().err();
+        }
+    }
+
+    fn register_current_state(&mut self, _item: &Self::FringeItem) -> Self::CurrentStateContext {}
+
+    fn prepare_state(&self, _context: &Self::CurrentStateContext, state: S) -> Self::FringeItem {
+        NoContext(state)
+    }
+
+    fn next_states_iter(
+        current_state: &Self::FringeItem,
+    ) -> impl Iterator<Item = Self::NextStatesIterItem> {
+        current_state.as_ref().next_states()
+    }
+
+    fn fringe_size(&self) -> usize {
+        let layer = self
+            .layer
+            .as_ref()
+            .map_or(0, |(_, reader)| reader.remaining);
+        layer + self.buffer.len() + self.runs.iter().map(|run| run.len).sum::<usize>()
+    }
+
+    fn explored_size(&self) -> usize {
+        self.explored.as_ref().map_or(0, |explored| explored.len)
+    }
+
+    fn failed(&self) -> bool {
+        self.error.is_some()
+    }
+}
+
+impl<S> Drop for Manager<S> {
+    fn drop(&mut self) {
+        self.layer = None;
+        if let Some(directory) = &self.directory {
+            // failing to clean up leaves the files behind in the spill directory, harmless to the caller
+            let _ = fs::remove_dir_all(directory);
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use crate::*;
+
+    #[derive(
+        Clone, Debug, PartialEq, Eq, PartialOrd, Ord, serde::Serialize, serde::Deserialize,
+    )]
+    struct Pos(i32, i32);
+
+    impl Searchable for Pos {
+        fn next_states(&self) -> impl Iterator<Item = Self> {
+            let &Pos(x, y) = self;
+            [Pos(x - 1, y), Pos(x, y - 1), Pos(x + 1, y), Pos(x, y + 1)].into_iter()
+        }
+    }
+
+    impl SolutionIdentifiable for Pos {
+        fn is_solution(&self) -> bool {
+            let &Pos(x, y) = self;
+            x == 5 && y == 5
+        }
+    }
+
+    let mut searcher: Searcher<Manager<_>> = Searcher::new(Pos(0, 0));
+    searcher.manager.buffer_capacity = 8;
+    assert_eq!(searcher.next(), Some(Pos(5, 5)));
+}
+
+#[test]
+fn test_exhaustive() {
+    use crate::*;
+
+    #[derive(
+        Clone, Debug, PartialEq, Eq, PartialOrd, Ord, serde::Serialize, serde::Deserialize,
+    )]
+    struct Pos(u8, u8);
+
+    impl Searchable for Pos {
+        fn next_states(&self) -> impl Iterator<Item = Self> {
+            let &Pos(x, y) = self;
+            [
+                x.checked_sub(1).map(|x| Pos(x, y)),
+                y.checked_sub(1).map(|y| Pos(x, y)),
+                (x < 9).then_some(Pos(x + 1, y)),
+                (y < 9).then_some(Pos(x, y + 1)),
+            ]
+            .into_iter()
+            .flatten()
+        }
+    }
+
+    impl SolutionIdentifiable for Pos {
+        fn is_solution(&self) -> bool {
+            false
+        }
+    }
+
+    let mut searcher: Searcher<Manager<_>> = Searcher::new_multi([Pos(0, 0), Pos(9, 9)]);
+    searcher.manager.buffer_capacity = 16;
+    let directory = searcher.manager.spill_directory.clone();
+    assert_eq!(searcher.next(), None);
+    assert_eq!(searcher.stats.states_expanded, 100);
+    assert_eq!(searcher.stats.explored_size, 100);
+
+    let spilled = searcher.manager.directory.clone().unwrap();
+    assert!(spilled.starts_with(directory));
+    drop(searcher);
+    assert!(!spilled.exists());
+}
+
+#[test]
+fn test_io_error() {
+    use crate::*;
+
+    #[derive(
+        Clone, Debug, PartialEq, Eq, PartialOrd, Ord, serde::Serialize, serde::Deserialize,
+    )]
+    struct Pos(i32, i32);
+
+    impl Searchable for Pos {
+        fn next_states(&self) -> impl Iterator<Item = Self> {
+            let &Pos(x, y) = self;
+            [Pos(x - 1, y), Pos(x, y - 1), Pos(x + 1, y), Pos(x, y + 1)].into_iter()
+        }
+    }
+
+    impl SolutionIdentifiable for Pos {
+        fn is_solution(&self) -> bool {
+            let &Pos(x, y) = self;
+            x == 5 && y == 5
+        }
+    }
+
+    // a file in place of the spill directory cannot have directories created inside it
+    let file = env::temp_dir().join(format!("space-search-{}-not-a-directory", process::id()));
+    File::create(&file).unwrap();
+    let mut searcher: Searcher<Manager<_>> = Searcher::new(Pos(0, 0));
+    searcher.manager.spill_directory = file.clone();
+    assert_eq!(searcher.try_next(), SearchOutcome::Failed);
+    assert!(searcher.manager.error().is_some());
+    assert_eq!(searcher.next(), None);
+    fs::remove_file(file).unwrap();
+}
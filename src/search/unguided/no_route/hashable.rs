@@ -6,6 +6,14 @@ use std::{
 use crate::{ExplorationManager, NoContext, Searchable};
 
 /// unguided, solution-only yielding, prior state exploration culling search manager.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(
+        serialize = "S: serde::Serialize",
+        deserialize = "S: serde::Deserialize<'de> + Eq + Hash"
+    ))
+)]
 pub struct Manager<S> {
     explored: HashSet<S>,
     fringe: VecDeque<S>,
@@ -28,10 +36,15 @@ where
 
     type NextStatesIterItem = S;
 
-    fn initialize(initial_state: S) -> Self {
+    fn initialize(initial_states: Vec<S>) -> Self {
+        let mut explored = HashSet::new();
+        let fringe = initial_states
+            .into_iter()
+            .filter(|state| explored.insert(state.clone()))
+            .collect();
         Self {
-            explored: HashSet::from([initial_state.clone()]),
-            fringe: VecDeque::from([initial_state]),
+            explored,
+            fringe,
             depth_first: false,
         }
     }
@@ -67,8 +80,18 @@ where
         NoContext(state)
     }
 
-    fn next_states_iter(current_state: &S) -> impl Iterator<Item = Self::NextStatesIterItem> {
-        current_state.next_states()
+    fn next_states_iter(
+        current_state: &Self::FringeItem,
+    ) -> impl Iterator<Item = Self::NextStatesIterItem> {
+        current_state.as_ref().next_states()
+    }
+
+    fn fringe_size(&self) -> usize {
+        self.fringe.len()
+    }
+
+    fn explored_size(&self) -> usize {
+        self.explored.len()
     }
 }
 
@@ -97,3 +120,76 @@ fn test() {
     let mut searcher: Searcher<Manager<_>> = Searcher::new(Pos(0, 0));
     assert_eq!(searcher.next(), Some(Pos(5, 5)));
 }
+
+#[test]
+fn test_stats() {
+    use crate::*;
+
+    #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+    struct Node(u8);
+
+    impl Searchable for Node {
+        fn next_states(&self) -> impl Iterator<Item = Self> {
+            let &Node(n) = self;
+            [n.checked_sub(1), (n < 3).then_some(n + 1)]
+                .into_iter()
+                .flatten()
+                .map(Node)
+        }
+    }
+
+    impl SolutionIdentifiable for Node {
+        fn is_solution(&self) -> bool {
+            self.0 == 3
+        }
+    }
+
+    let mut searcher: Searcher<Manager<_>> = Searcher::new(Node(0));
+    assert_eq!(searcher.next(), Some(Node(3)));
+    assert_eq!(
+        searcher.stats,
+        SearchStats {
+            states_expanded: 3,
+            states_generated: 5,
+            duplicates_culled: 2,
+            limits_culled: 0,
+            peak_fringe_size: 1,
+            explored_size: 4,
+            solutions_yielded: 1,
+        }
+    );
+}
+
+#[test]
+fn test_try_next() {
+    use crate::*;
+
+    #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+    struct Node(u8);
+
+    impl Searchable for Node {
+        fn next_states(&self) -> impl Iterator<Item = Self> {
+            let &Node(n) = self;
+            [n.checked_sub(1), (n < 3).then_some(n + 1)]
+                .into_iter()
+                .flatten()
+                .map(Node)
+        }
+    }
+
+    impl SolutionIdentifiable for Node {
+        fn is_solution(&self) -> bool {
+            self.0 == 3
+        }
+    }
+
+    let mut searcher: Searcher<Manager<_>> = Searcher::new(Node(0));
+    searcher.max_expansions = Some(2);
+    assert_eq!(
+        searcher.try_next(),
+        SearchOutcome::LimitReached(SearchLimit::Expansions)
+    );
+    searcher.max_expansions = None;
+    assert_eq!(searcher.try_next(), SearchOutcome::Solution(Node(3)));
+    assert_eq!(searcher.try_next(), SearchOutcome::Exhausted);
+}
@@ -0,0 +1,193 @@
+use std::cell::RefCell;
+
+use crate::{ExplorationManager, SearchLimit, Searchable, StateDepth};
+
+/// unguided, solution-only yielding, current path cycle culling, iterative-deepening depth-first search manager.
+///
+/// Repeats a depth-limited depth-first search with an increasing depth limit, yielding the same
+/// shortest-step solutions as breadth-first search while only keeping the current path in memory.
+/// Yields every solution found at the shallowest depth containing one, then stops. A solution reached
+/// through several paths of that depth is only yielded once.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(
+        serialize = "S: serde::Serialize",
+        deserialize = "S: serde::Deserialize<'de>"
+    ))
+)]
+pub struct Manager<S> {
+    initial_states: Vec<S>,
+    fringe: Vec<StateDepth<S>>,
+    path: Vec<S>,
+    depth_limit: usize,
+    cutoff: bool,
+    solutions: RefCell<Vec<S>>,
+
+    /// Maximum number of steps to deepen the search to. By default, the search deepens until a solution
+    /// is found. Set this to give up on infinite or very deep search spaces with no shallow solutions.
+    pub max_depth: usize,
+}
+
+impl<S> ExplorationManager for Manager<S>
+where
+    S: Searchable + Clone + PartialEq,
+{
+    type State = S;
+    type YieldResult = S;
+
+    type FringeItem = StateDepth<S>;
+
+    type CurrentStateContext = usize;
+
+    type NextStatesIterItem = S;
+
+    fn initialize(initial_states: Vec<S>) -> Self {
+        Self {
+            fringe: initial_states
+                .iter()
+                .map(|state| StateDepth {
+                    state: state.clone(),
+                    depth: 0,
+                })
+                .collect(),
+            initial_states,
+            path: Vec::new(),
+            depth_limit: 0,
+            cutoff: false,
+            solutions: RefCell::new(Vec::new()),
+            max_depth: usize::MAX,
+        }
+    }
+
+    fn pop_state(&mut self) -> Option<Self::FringeItem> {
+        loop {
+            let Some(item) = self.fringe.pop() else {
+                // iteration exhausted; deepen if any state was cut off by the current limit
+                if !self.solutions.get_mut().is_empty() || !self.cutoff {
+                    return None;
+                }
+                if self.depth_limit >= self.max_depth {
+                    return None;
+                }
+                self.depth_limit += 1;
+                self.cutoff = false;
+                self.path.clear();
+                self.fringe
+                    .extend(self.initial_states.iter().map(|state| StateDepth {
+                        state: state.clone(),
+                        depth: 0,
+                    }));
+                return self.fringe.pop();
+            };
+            // solutions are only found at a single depth, so any state already yielded is reached
+            // again through another path of the same length
+            if self.solutions.get_mut().contains(&item.state) {
+                continue;
+            }
+            self.path.truncate(item.depth);
+            return Some(item);
+        }
+    }
+
+    fn prepare_result_from(&self, item: Self::FringeItem) -> Self::YieldResult {
+        self.solutions.borrow_mut().push(item.state.clone());
+        item.state
+    }
+
+    fn exceeds_limit(&mut self, item: &Self::FringeItem) -> bool {
+        // states already on the current path are culled as duplicates, whatever their depth
+        let exceeds = item.depth > self.depth_limit && !self.path.contains(&item.state);
+        self.cutoff |= exceeds;
+        exceeds
+    }
+
+    fn valid_state(&mut self, item: &Self::FringeItem) -> bool {
+        !self.path.contains(&item.state)
+    }
+
+    fn place_state(&mut self, item: Self::FringeItem) {
+        self.fringe.push(item);
+    }
+
+    fn register_current_state(&mut self, item: &Self::FringeItem) -> Self::CurrentStateContext {
+        self.path.push(item.state.clone());
+        item.depth + 1
+    }
+
+    fn prepare_state(&self, depth: &Self::CurrentStateContext, state: S) -> Self::FringeItem {
+        StateDepth {
+            state,
+            depth: *depth,
+        }
+    }
+
+    fn next_states_iter(
+        current_state: &Self::FringeItem,
+    ) -> impl Iterator<Item = Self::NextStatesIterItem> {
+        current_state.as_ref().next_states()
+    }
+
+    fn fringe_size(&self) -> usize {
+        self.fringe.len()
+    }
+
+    fn explored_size(&self) -> usize {
+        self.path.len()
+    }
+
+    fn limit_reached(&self) -> Option<SearchLimit> {
+        (self.cutoff && self.solutions.borrow().is_empty()).then_some(SearchLimit::Depth)
+    }
+}
+
+#[test]
+fn test() {
+    use crate::*;
+
+    #[derive(Clone, Debug, PartialEq)]
+    struct Pos(i32, i32);
+
+    impl Searchable for Pos {
+        fn next_states(&self) -> impl Iterator<Item = Self> {
+            let &Pos(x, y) = self;
+            [Pos(x - 1, y), Pos(x, y - 1), Pos(x + 1, y), Pos(x, y + 1)].into_iter()
+        }
+    }
+
+    impl SolutionIdentifiable for Pos {
+        fn is_solution(&self) -> bool {
+            let &Pos(x, y) = self;
+            x == 5 && y == 5
+        }
+    }
+
+    let mut searcher: Searcher<Manager<_>> = Searcher::new(Pos(0, 0));
+    assert_eq!(searcher.next(), Some(Pos(5, 5)));
+}
+
+#[test]
+fn test_repeated_solution() {
+    use crate::*;
+
+    #[derive(Clone, Debug, PartialEq)]
+    struct Pos(i32, i32);
+
+    impl Searchable for Pos {
+        fn next_states(&self) -> impl Iterator<Item = Self> {
+            let &Pos(x, y) = self;
+            [Pos(x - 1, y), Pos(x, y - 1), Pos(x + 1, y), Pos(x, y + 1)].into_iter()
+        }
+    }
+
+    impl SolutionIdentifiable for Pos {
+        fn is_solution(&self) -> bool {
+            let &Pos(x, y) = self;
+            x == 1 && y == 1
+        }
+    }
+
+    // reached through both Pos(1, 0) and Pos(0, 1)
+    let searcher: Searcher<Manager<_>> = Searcher::new(Pos(0, 0));
+    assert_eq!(searcher.collect::<Vec<_>>(), vec![Pos(1, 1)]);
+}
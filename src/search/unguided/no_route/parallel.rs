@@ -0,0 +1,192 @@
+use std::{cell::Cell, collections::HashSet, hash::Hash};
+
+use rayon::prelude::*;
+
+use crate::{Budget, ExplorationManager, NoContext, Searchable, SuccessorsItem};
+
+/// Parallel breadth-first, solution-only yielding, prior state exploration culling search manager.
+///
+/// Generates the successors of the states of a depth layer concurrently on the rayon thread pool,
+/// ahead of the states being popped, and checks the searcher's limits before generating the
+/// successors of each state. The searcher then culls the successors of each popped state against
+/// the states already explored, as it would for any other manager.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(
+        serialize = "S: serde::Serialize",
+        deserialize = "S: serde::Deserialize<'de> + Eq + Hash"
+    ))
+)]
+pub struct Manager<S> {
+    explored: HashSet<S>,
+    layer: Vec<S>,
+    generated: Vec<(S, Vec<S>)>,
+    next_layer: Vec<S>,
+}
+
+impl<S> ExplorationManager for Manager<S>
+where
+    S: Searchable + Clone + Eq + Hash + Send + Sync,
+{
+    type State = S;
+    type YieldResult = S;
+
+    type FringeItem = SuccessorsItem<NoContext<S>, S>;
+
+    type CurrentStateContext = ();
+
+    type NextStatesIterItem = S;
+
+    fn initialize(initial_states: Vec<S>) -> Self {
+        let mut explored = HashSet::new();
+        let layer = initial_states
+            .into_iter()
+            .filter(|state| explored.insert(state.clone()))
+            .collect();
+        Self {
+            explored,
+            layer,
+            generated: Vec::new(),
+            next_layer: Vec::new(),
+        }
+    }
+
+    fn pop_state(&mut self) -> Option<Self::FringeItem> {
+        self.pop_state_within(&mut Budget::default())
+    }
+
+    fn pop_state_within(&mut self, budget: &mut Budget) -> Option<Self::FringeItem> {
+        if self.generated.is_empty() {
+            if self.layer.is_empty() {
+                std::mem::swap(&mut self.layer, &mut self.next_layer);
+            }
+            // never generate the successors of more states than the searcher may still expand
+            let count = budget.expansions().map_or(self.layer.len(), |expansions| {
+                expansions.min(self.layer.len())
+            });
+            let parents = self.layer.split_off(self.layer.len() - count);
+            let limits = &*budget;
+            let successors: Vec<_> = parents
+                .into_par_iter()
+                .map(|parent| {
+                    let successors =
+                        (!limits.spent()).then(|| parent.next_states().collect::<Vec<_>>());
+                    (parent, successors)
+                })
+                .collect();
+            for (parent, successors) in successors {
+                match successors {
+                    Some(successors) => self.generated.push((parent, successors)),
+                    None => self.layer.push(parent),
+                }
+            }
+            if self.generated.is_empty() && !self.layer.is_empty() {
+                budget.record(0, 0, true);
+                return None;
+            }
+        }
+        let (state, successors) = self.generated.pop()?;
+        Some(SuccessorsItem {
+            item: NoContext(state),
+            successors: Cell::new(successors),
+        })
+    }
+
+    fn prepare_result_from(
+        &self,
+        SuccessorsItem { item, .. }: Self::FringeItem,
+    ) -> Self::YieldResult {
+        item.0
+    }
+
+    fn valid_state(&mut self, SuccessorsItem { item, .. }: &Self::FringeItem) -> bool {
+        !self.explored.contains(&item.0)
+    }
+
+    fn place_state(&mut self, SuccessorsItem { item, .. }: Self::FringeItem) {
+        self.explored.insert(item.0.clone());
+        self.next_layer.push(item.0);
+    }
+
+    fn register_current_state(&mut self, _item: &Self::FringeItem) -> Self::CurrentStateContext {}
+
+    fn prepare_state(&self, _context: &Self::CurrentStateContext, state: S) -> Self::FringeItem {
+        SuccessorsItem {
+            item: NoContext(state),
+            successors: Cell::default(),
+        }
+    }
+
+    fn next_states_iter(
+        current_state: &Self::FringeItem,
+    ) -> impl Iterator<Item = Self::NextStatesIterItem> {
+        current_state.successors.take().into_iter()
+    }
+
+    fn fringe_size(&self) -> usize {
+        self.layer.len() + self.generated.len() + self.next_layer.len()
+    }
+
+    fn explored_size(&self) -> usize {
+        self.explored.len()
+    }
+}
+
+#[test]
+fn test() {
+    use crate::*;
+
+    #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+    struct Pos(i32, i32);
+
+    impl Searchable for Pos {
+        fn next_states(&self) -> impl Iterator<Item = Self> {
+            let &Pos(x, y) = self;
+            [Pos(x - 1, y), Pos(x, y - 1), Pos(x + 1, y), Pos(x, y + 1)].into_iter()
+        }
+    }
+
+    impl SolutionIdentifiable for Pos {
+        fn is_solution(&self) -> bool {
+            let &Pos(x, y) = self;
+            x == 5 && y == 5
+        }
+    }
+
+    let mut searcher: Searcher<Manager<_>> = Searcher::new(Pos(0, 0));
+    assert_eq!(searcher.next(), Some(Pos(5, 5)));
+}
+
+#[test]
+fn test_stats() {
+    use crate::*;
+
+    #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+    struct Pos(i32, i32);
+
+    impl Searchable for Pos {
+        fn next_states(&self) -> impl Iterator<Item = Self> {
+            let &Pos(x, y) = self;
+            [Pos(x - 1, y), Pos(x, y - 1), Pos(x + 1, y), Pos(x, y + 1)].into_iter()
+        }
+    }
+
+    impl SolutionIdentifiable for Pos {
+        fn is_solution(&self) -> bool {
+            let &Pos(x, y) = self;
+            x == 5 && y == 5
+        }
+    }
+
+    let mut searcher: Searcher<Manager<_>> = Searcher::new(Pos(0, 0));
+    assert_eq!(searcher.next(), Some(Pos(5, 5)));
+    assert_eq!(
+        searcher.stats.states_generated,
+        4 * searcher.stats.states_expanded
+    );
+    assert_eq!(
+        searcher.stats.states_generated - searcher.stats.duplicates_culled + 1,
+        searcher.stats.explored_size
+    );
+}
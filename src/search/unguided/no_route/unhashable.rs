@@ -3,6 +3,14 @@ use std::collections::VecDeque;
 use crate::{ExplorationManager, NoContext, Searchable};
 
 /// unguided, solution-only yielding, unoptimized culling search manager.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(
+        serialize = "S: serde::Serialize",
+        deserialize = "S: serde::Deserialize<'de>"
+    ))
+)]
 pub struct Manager<S> {
     fringe: VecDeque<S>,
 
@@ -24,9 +32,9 @@ where
 
     type NextStatesIterItem = S;
 
-    fn initialize(initial_state: S) -> Self {
+    fn initialize(initial_states: Vec<S>) -> Self {
         Self {
-            fringe: VecDeque::from([initial_state]),
+            fringe: VecDeque::from(initial_states),
             depth_first: false,
         }
     }
@@ -57,8 +65,18 @@ where
         NoContext(state)
     }
 
-    fn next_states_iter(current_state: &S) -> impl Iterator<Item = Self::NextStatesIterItem> {
-        current_state.next_states()
+    fn next_states_iter(
+        current_state: &Self::FringeItem,
+    ) -> impl Iterator<Item = Self::NextStatesIterItem> {
+        current_state.as_ref().next_states()
+    }
+
+    fn fringe_size(&self) -> usize {
+        self.fringe.len()
+    }
+
+    fn explored_size(&self) -> usize {
+        0
     }
 }
 
@@ -86,3 +104,37 @@ fn test() {
     let mut searcher: Searcher<Manager<_>> = Searcher::new(Pos(0, 0));
     assert_eq!(searcher.next(), Some(Pos(5, 5)));
 }
+
+#[test]
+fn test_cancellation() {
+    use crate::*;
+    use std::{thread, time::Duration};
+
+    #[derive(Clone, Debug, PartialEq)]
+    struct Pos(i32, i32);
+
+    impl Searchable for Pos {
+        fn next_states(&self) -> impl Iterator<Item = Self> {
+            let &Pos(x, y) = self;
+            [Pos(x - 1, y), Pos(x, y - 1), Pos(x + 1, y), Pos(x, y + 1)].into_iter()
+        }
+    }
+
+    impl SolutionIdentifiable for Pos {
+        fn is_solution(&self) -> bool {
+            false
+        }
+    }
+
+    let token = CancellationToken::new();
+    let mut searcher: Searcher<Manager<_>> = Searcher::new(Pos(0, 0));
+    searcher.cancellation = Some(token.clone());
+
+    let search = thread::spawn(move || (searcher.try_next(), searcher));
+    thread::sleep(Duration::from_millis(10));
+    token.cancel();
+
+    let (outcome, searcher) = search.join().unwrap();
+    assert_eq!(outcome, SearchOutcome::Cancelled);
+    assert!(searcher.stats.states_expanded > 0);
+}
@@ -1,4 +1,8 @@
 //! Module containing all unguided, solution-route yielding search managers.
 
+pub mod depth_limited;
 pub mod hashable;
+pub mod iterative_deepening;
+#[cfg(feature = "parallel")]
+pub mod parallel;
 pub mod unhashable;
@@ -0,0 +1,132 @@
+use crate::{ExplorationManager, SearchLimit, Searchable, StateDepth};
+
+/// unguided, solution-route yielding, current path cycle culling, depth-limited depth-first search manager.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(
+        serialize = "S: serde::Serialize",
+        deserialize = "S: serde::Deserialize<'de>"
+    ))
+)]
+pub struct Manager<S> {
+    fringe: Vec<StateDepth<S>>,
+    path: Vec<S>,
+    cutoff: bool,
+
+    /// Maximum number of steps taken from the initial state. By default, no limit is applied.
+    /// Set this to bound the depth-first search on infinite or very deep search spaces.
+    pub depth_limit: usize,
+}
+
+impl<S> ExplorationManager for Manager<S>
+where
+    S: Searchable + Clone + PartialEq,
+{
+    type State = S;
+    type YieldResult = Vec<S>;
+
+    type FringeItem = StateDepth<S>;
+
+    type CurrentStateContext = usize;
+
+    type NextStatesIterItem = S;
+
+    fn initialize(initial_states: Vec<S>) -> Self {
+        Self {
+            fringe: initial_states
+                .into_iter()
+                .map(|state| StateDepth { state, depth: 0 })
+                .collect(),
+            path: Vec::new(),
+            cutoff: false,
+            depth_limit: usize::MAX,
+        }
+    }
+
+    fn pop_state(&mut self) -> Option<Self::FringeItem> {
+        let item = self.fringe.pop()?;
+        self.path.truncate(item.depth);
+        Some(item)
+    }
+
+    fn prepare_result_from(&self, item: Self::FringeItem) -> Self::YieldResult {
+        let mut result = self.path.clone();
+        result.push(item.state);
+        result
+    }
+
+    fn exceeds_limit(&mut self, item: &Self::FringeItem) -> bool {
+        // states already on the current path are culled as duplicates, whatever their depth
+        let exceeds = item.depth > self.depth_limit && !self.path.contains(&item.state);
+        self.cutoff |= exceeds;
+        exceeds
+    }
+
+    fn valid_state(&mut self, item: &Self::FringeItem) -> bool {
+        !self.path.contains(&item.state)
+    }
+
+    fn place_state(&mut self, item: Self::FringeItem) {
+        self.fringe.push(item);
+    }
+
+    fn register_current_state(&mut self, item: &Self::FringeItem) -> Self::CurrentStateContext {
+        self.path.push(item.state.clone());
+        item.depth + 1
+    }
+
+    fn prepare_state(&self, depth: &Self::CurrentStateContext, state: S) -> Self::FringeItem {
+        StateDepth {
+            state,
+            depth: *depth,
+        }
+    }
+
+    fn next_states_iter(
+        current_state: &Self::FringeItem,
+    ) -> impl Iterator<Item = Self::NextStatesIterItem> {
+        current_state.as_ref().next_states()
+    }
+
+    fn fringe_size(&self) -> usize {
+        self.fringe.len()
+    }
+
+    fn explored_size(&self) -> usize {
+        self.path.len()
+    }
+
+    fn limit_reached(&self) -> Option<SearchLimit> {
+        self.cutoff.then_some(SearchLimit::Depth)
+    }
+}
+
+#[test]
+fn test() {
+    use crate::*;
+
+    #[derive(Clone, Debug, PartialEq)]
+    struct Pos(i32, i32);
+
+    impl Searchable for Pos {
+        fn next_states(&self) -> impl Iterator<Item = Self> {
+            let &Pos(x, y) = self;
+            [Pos(x - 1, y), Pos(x, y - 1), Pos(x + 1, y), Pos(x, y + 1)].into_iter()
+        }
+    }
+
+    impl SolutionIdentifiable for Pos {
+        fn is_solution(&self) -> bool {
+            let &Pos(x, y) = self;
+            x == 2 && y == 2
+        }
+    }
+
+    let mut searcher: Searcher<Manager<_>> = Searcher::new(Pos(0, 0));
+    searcher.manager.depth_limit = 4;
+    assert_eq!(
+        searcher.next(),
+        Some(vec![Pos(0, 0), Pos(0, 1), Pos(0, 2), Pos(1, 2), Pos(2, 2)])
+    );
+}
@@ -1,15 +1,22 @@
-use std::{
-    collections::{HashSet, VecDeque},
-    hash::Hash,
-};
+use std::{collections::VecDeque, hash::Hash, sync::Arc};
 
-use crate::{prepare_result_from_state_parent_map, ExplorationManager, Searchable, StateParent};
+use crate::{
+    prepare_result_from_state_parent_map, ExplorationManager, NodeItem, Nodes, Searchable,
+    StateParent,
+};
 
 /// unguided, solution-route yielding, prior state exploration culling search manager.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(
+        serialize = "S: serde::Serialize",
+        deserialize = "S: serde::Deserialize<'de> + Eq + Hash"
+    ))
+)]
 pub struct Manager<S> {
-    explored: HashSet<S>,
-    fringe: VecDeque<StateParent<S>>,
-    parents: Vec<StateParent<S>>,
+    explored: Nodes<S, StateParent<S>>,
+    fringe: VecDeque<usize>,
 
     /// Toggle depth-first searching on. By default, breadth-first search is used.
     /// Enable this flag to perform depth-first search instead.
@@ -23,63 +30,79 @@ where
     type State = S;
     type YieldResult = Vec<S>;
 
-    type FringeItem = StateParent<S>;
+    type FringeItem = NodeItem<StateParent<S>>;
 
     type CurrentStateContext = usize;
 
     type NextStatesIterItem = S;
 
-    fn initialize(initial_state: S) -> Self {
-        let initial_pair = StateParent {
-            state: initial_state.clone(),
-            parent: None,
-        };
+    fn initialize(initial_states: Vec<S>) -> Self {
+        let mut explored = Nodes::new();
+        let fringe = initial_states
+            .into_iter()
+            .filter_map(|state| {
+                explored.insert(StateParent {
+                    state: Arc::new(state),
+                    parent: None,
+                })
+            })
+            .collect();
         Self {
-            explored: HashSet::from([initial_state]),
-            fringe: VecDeque::from([initial_pair.clone()]),
-            parents: vec![initial_pair],
+            explored,
+            fringe,
             depth_first: false,
         }
     }
 
     fn pop_state(&mut self) -> Option<Self::FringeItem> {
-        match self.depth_first {
+        let index = match self.depth_first {
             true => self.fringe.pop_back(),
             false => self.fringe.pop_front(),
-        }
+        }?;
+        Some(NodeItem {
+            item: self.explored[index].clone(),
+            node: Some(index),
+        })
     }
 
-    fn prepare_result_from(&self, item: Self::FringeItem) -> Self::YieldResult {
-        prepare_result_from_state_parent_map(&self.parents, item)
+    fn prepare_result_from(&self, NodeItem { item, .. }: Self::FringeItem) -> Self::YieldResult {
+        prepare_result_from_state_parent_map(&self.explored, item)
     }
 
-    fn valid_state(&mut self, StateParent { state, parent: _ }: &Self::FringeItem) -> bool {
-        if !self.explored.contains(state) {
-            self.explored.insert(state.clone());
-            true
-        } else {
-            false
-        }
+    fn valid_state(&mut self, NodeItem { item, .. }: &Self::FringeItem) -> bool {
+        !self.explored.contains(&item.state)
     }
 
-    fn place_state(&mut self, item: Self::FringeItem) {
-        self.fringe.push_back(item);
+    fn place_state(&mut self, NodeItem { item, .. }: Self::FringeItem) {
+        self.fringe.extend(self.explored.insert(item));
     }
 
     fn register_current_state(&mut self, item: &Self::FringeItem) -> Self::CurrentStateContext {
-        self.parents.push(item.clone());
-        self.parents.len() - 1
+        item.node.expect("Popped items always carry their node")
     }
 
     fn prepare_state(&self, context: &Self::CurrentStateContext, state: S) -> Self::FringeItem {
-        StateParent {
-            state,
-            parent: Some(*context),
+        NodeItem {
+            item: StateParent {
+                state: Arc::new(state),
+                parent: Some(*context),
+            },
+            node: None,
         }
     }
 
-    fn next_states_iter(current_state: &S) -> impl Iterator<Item = Self::NextStatesIterItem> {
-        current_state.next_states()
+    fn next_states_iter(
+        current_state: &Self::FringeItem,
+    ) -> impl Iterator<Item = Self::NextStatesIterItem> {
+        current_state.as_ref().next_states()
+    }
+
+    fn fringe_size(&self) -> usize {
+        self.fringe.len()
+    }
+
+    fn explored_size(&self) -> usize {
+        self.explored.len()
     }
 }
 
@@ -122,3 +145,108 @@ fn test() {
         ])
     );
 }
+
+#[test]
+fn test_observer() {
+    use crate::*;
+
+    #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+    struct Node(u8);
+
+    impl Searchable for Node {
+        fn next_states(&self) -> impl Iterator<Item = Self> {
+            let &Node(n) = self;
+            [n.checked_sub(1), (n < 2).then_some(n + 1)]
+                .into_iter()
+                .flatten()
+                .map(Node)
+        }
+    }
+
+    impl SolutionIdentifiable for Node {
+        fn is_solution(&self) -> bool {
+            self.0 == 2
+        }
+    }
+
+    #[derive(Default)]
+    struct Trace(Vec<String>);
+
+    impl SearchObserver<Node> for Trace {
+        fn state_popped(&mut self, state: &Node) {
+            self.0.push(format!("popped {}", state.0));
+        }
+
+        fn state_expanded(&mut self, state: &Node) {
+            self.0.push(format!("expanded {}", state.0));
+        }
+
+        fn successor_generated(&mut self, state: &Node) {
+            self.0.push(format!("generated {}", state.0));
+        }
+
+        fn successor_rejected(&mut self, state: &Node) {
+            self.0.push(format!("rejected {}", state.0));
+        }
+
+        fn solution_found(&mut self, state: &Node) {
+            self.0.push(format!("solution {}", state.0));
+        }
+    }
+
+    let mut searcher = Searcher::<Manager<_>>::new(Node(0)).with_observer(Trace::default());
+    assert_eq!(searcher.next(), Some(vec![Node(0), Node(1), Node(2)]));
+    assert_eq!(
+        searcher.observer.0,
+        [
+            "popped 0",
+            "generated 1",
+            "expanded 0",
+            "popped 1",
+            "generated 0",
+            "rejected 0",
+            "generated 2",
+            "expanded 1",
+            "popped 2",
+            "solution 2",
+        ]
+    );
+}
+
+#[test]
+fn test_states_cloned_only_for_routes() {
+    use crate::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    static CLONES: AtomicUsize = AtomicUsize::new(0);
+
+    #[derive(Debug, PartialEq, Eq, Hash)]
+    struct Pos(i32, i32);
+
+    impl Clone for Pos {
+        fn clone(&self) -> Self {
+            CLONES.fetch_add(1, Ordering::Relaxed);
+            Pos(self.0, self.1)
+        }
+    }
+
+    impl Searchable for Pos {
+        fn next_states(&self) -> impl Iterator<Item = Self> {
+            let &Pos(x, y) = self;
+            [Pos(x - 1, y), Pos(x, y - 1), Pos(x + 1, y), Pos(x, y + 1)].into_iter()
+        }
+    }
+
+    impl SolutionIdentifiable for Pos {
+        fn is_solution(&self) -> bool {
+            let &Pos(x, y) = self;
+            x == 5 && y == 5
+        }
+    }
+
+    let mut searcher: Searcher<Manager<_>> = Searcher::new(Pos(0, 0));
+    let route = searcher.next().unwrap();
+    assert_eq!(route.len(), 11);
+    assert!(searcher.stats.explored_size > 11);
+    assert_eq!(CLONES.load(Ordering::Relaxed), route.len());
+}
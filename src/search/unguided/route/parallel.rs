@@ -0,0 +1,345 @@
+use std::{cell::Cell, hash::Hash, sync::Arc};
+
+use rayon::prelude::*;
+
+use crate::{
+    prepare_result_from_state_parent_map, Budget, ExplorationManager, NodeItem, Nodes, Searchable,
+    StateParent, SuccessorsItem,
+};
+
+/// Parallel breadth-first, solution-route yielding, prior state exploration culling search manager.
+///
+/// Generates the successors of the states of a depth layer concurrently on the rayon thread pool,
+/// ahead of the states being popped, and checks the searcher's limits before generating the
+/// successors of each state. The searcher then culls the successors of each popped state against
+/// the states already explored, as it would for any other manager, and the new states are stored
+/// once in the table of explored states, along with the index of their parent state. Yielded routes
+/// are shortest-step routes.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(
+        serialize = "S: serde::Serialize",
+        deserialize = "S: serde::Deserialize<'de> + Eq + Hash"
+    ))
+)]
+pub struct Manager<S> {
+    explored: Nodes<S, StateParent<S>>,
+    layer: Vec<usize>,
+    generated: Vec<(usize, Vec<S>)>,
+    next_layer: Vec<usize>,
+}
+
+impl<S> ExplorationManager for Manager<S>
+where
+    S: Searchable + Clone + Eq + Hash + Send + Sync,
+{
+    type State = S;
+    type YieldResult = Vec<S>;
+
+    type FringeItem = SuccessorsItem<NodeItem<StateParent<S>>, S>;
+
+    type CurrentStateContext = usize;
+
+    type NextStatesIterItem = S;
+
+    fn initialize(initial_states: Vec<S>) -> Self {
+        let mut explored = Nodes::new();
+        let layer = initial_states
+            .into_iter()
+            .filter_map(|state| {
+                explored.insert(StateParent {
+                    state: Arc::new(state),
+                    parent: None,
+                })
+            })
+            .collect();
+        Self {
+            explored,
+            layer,
+            generated: Vec::new(),
+            next_layer: Vec::new(),
+        }
+    }
+
+    fn pop_state(&mut self) -> Option<Self::FringeItem> {
+        self.pop_state_within(&mut Budget::default())
+    }
+
+    fn pop_state_within(&mut self, budget: &mut Budget) -> Option<Self::FringeItem> {
+        if self.generated.is_empty() {
+            if self.layer.is_empty() {
+                std::mem::swap(&mut self.layer, &mut self.next_layer);
+            }
+            // never generate the successors of more states than the searcher may still expand
+            let count = budget.expansions().map_or(self.layer.len(), |expansions| {
+                expansions.min(self.layer.len())
+            });
+            let parents = self.layer.split_off(self.layer.len() - count);
+            let explored = &self.explored;
+            let limits = &*budget;
+            let successors: Vec<_> = parents
+                .into_par_iter()
+                .map(|parent| {
+                    let successors = (!limits.spent())
+                        .then(|| explored[parent].state.next_states().collect::<Vec<_>>());
+                    (parent, successors)
+                })
+                .collect();
+            for (parent, successors) in successors {
+                match successors {
+                    Some(successors) => self.generated.push((parent, successors)),
+                    None => self.layer.push(parent),
+                }
+            }
+            if self.generated.is_empty() && !self.layer.is_empty() {
+                budget.record(0, 0, true);
+                return None;
+            }
+        }
+        let (index, successors) = self.generated.pop()?;
+        Some(SuccessorsItem {
+            item: NodeItem {
+                item: self.explored[index].clone(),
+                node: Some(index),
+            },
+            successors: Cell::new(successors),
+        })
+    }
+
+    fn prepare_result_from(
+        &self,
+        SuccessorsItem { item, .. }: Self::FringeItem,
+    ) -> Self::YieldResult {
+        prepare_result_from_state_parent_map(&self.explored, item.item)
+    }
+
+    fn valid_state(&mut self, SuccessorsItem { item, .. }: &Self::FringeItem) -> bool {
+        !self.explored.contains(&item.item.state)
+    }
+
+    fn place_state(&mut self, SuccessorsItem { item, .. }: Self::FringeItem) {
+        self.next_layer.extend(self.explored.insert(item.item));
+    }
+
+    fn register_current_state(
+        &mut self,
+        SuccessorsItem { item, .. }: &Self::FringeItem,
+    ) -> Self::CurrentStateContext {
+        item.node.expect("Popped items always carry their node")
+    }
+
+    fn prepare_state(&self, context: &Self::CurrentStateContext, state: S) -> Self::FringeItem {
+        SuccessorsItem {
+            item: NodeItem {
+                item: StateParent {
+                    state: Arc::new(state),
+                    parent: Some(*context),
+                },
+                node: None,
+            },
+            successors: Cell::default(),
+        }
+    }
+
+    fn next_states_iter(
+        current_state: &Self::FringeItem,
+    ) -> impl Iterator<Item = Self::NextStatesIterItem> {
+        current_state.successors.take().into_iter()
+    }
+
+    fn fringe_size(&self) -> usize {
+        self.layer.len() + self.generated.len() + self.next_layer.len()
+    }
+
+    fn explored_size(&self) -> usize {
+        self.explored.len()
+    }
+}
+
+#[test]
+fn test() {
+    use crate::*;
+
+    #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+    struct Pos(i32, i32);
+
+    impl Searchable for Pos {
+        fn next_states(&self) -> impl Iterator<Item = Self> {
+            let &Pos(x, y) = self;
+            [Pos(x - 1, y), Pos(x, y - 1), Pos(x + 1, y), Pos(x, y + 1)].into_iter()
+        }
+    }
+
+    impl SolutionIdentifiable for Pos {
+        fn is_solution(&self) -> bool {
+            let &Pos(x, y) = self;
+            x == 5 && y == 5
+        }
+    }
+
+    let mut searcher: Searcher<Manager<_>> = Searcher::new(Pos(0, 0));
+    let route = searcher.next().unwrap();
+    assert_eq!(route.len(), 11);
+    assert_eq!(route.first(), Some(&Pos(0, 0)));
+    assert_eq!(route.last(), Some(&Pos(5, 5)));
+    assert!(route
+        .windows(2)
+        .all(|step| step[0].next_states().any(|state| state == step[1])));
+}
+
+#[test]
+fn test_stats() {
+    use crate::*;
+
+    #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+    struct Pos(i32, i32);
+
+    impl Searchable for Pos {
+        fn next_states(&self) -> impl Iterator<Item = Self> {
+            let &Pos(x, y) = self;
+            [Pos(x - 1, y), Pos(x, y - 1), Pos(x + 1, y), Pos(x, y + 1)].into_iter()
+        }
+    }
+
+    impl SolutionIdentifiable for Pos {
+        fn is_solution(&self) -> bool {
+            let &Pos(x, y) = self;
+            x == 5 && y == 5
+        }
+    }
+
+    #[derive(Default)]
+    struct Count {
+        generated: usize,
+        rejected: usize,
+    }
+
+    impl SearchObserver<Pos> for Count {
+        fn successor_generated(&mut self, _state: &Pos) {
+            self.generated += 1;
+        }
+
+        fn successor_rejected(&mut self, _state: &Pos) {
+            self.rejected += 1;
+        }
+    }
+
+    let mut searcher = Searcher::<Manager<_>>::new(Pos(0, 0)).with_observer(Count::default());
+    assert_eq!(searcher.next().map(|route| route.len()), Some(11));
+    assert_eq!(
+        searcher.stats.states_generated,
+        4 * searcher.stats.states_expanded
+    );
+    assert_eq!(searcher.observer.generated, searcher.stats.states_generated);
+    assert_eq!(searcher.observer.rejected, searcher.stats.duplicates_culled);
+    assert_eq!(
+        searcher.stats.states_generated - searcher.stats.duplicates_culled + 1,
+        searcher.stats.explored_size
+    );
+}
+
+#[test]
+fn test_limits() {
+    use crate::*;
+    use std::{
+        thread,
+        time::{Duration, Instant},
+    };
+
+    /// A single wide layer of states that are slow to expand, without a solution.
+    #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+    enum Node {
+        Root,
+        Branch(u32),
+        Leaf(u32),
+    }
+
+    impl Searchable for Node {
+        fn next_states(&self) -> impl Iterator<Item = Self> {
+            let states: Vec<_> = match *self {
+                Node::Root => (0..10_000).map(Node::Branch).collect(),
+                Node::Branch(i) => {
+                    thread::sleep(Duration::from_millis(10));
+                    vec![Node::Leaf(i)]
+                }
+                Node::Leaf(_) => Vec::new(),
+            };
+            states.into_iter()
+        }
+    }
+
+    impl SolutionIdentifiable for Node {
+        fn is_solution(&self) -> bool {
+            false
+        }
+    }
+
+    let mut searcher: Searcher<Manager<_>> = Searcher::new(Node::Root);
+    let started = Instant::now();
+    searcher.deadline = Some(started + Duration::from_millis(20));
+    assert_eq!(
+        searcher.try_next(),
+        SearchOutcome::LimitReached(SearchLimit::Deadline)
+    );
+    assert!(started.elapsed() < Duration::from_secs(1));
+
+    let token = CancellationToken::new();
+    searcher.deadline = None;
+    searcher.cancellation = Some(token.clone());
+    let cancel = thread::spawn(move || {
+        thread::sleep(Duration::from_millis(20));
+        token.cancel();
+    });
+    assert_eq!(searcher.try_next(), SearchOutcome::Cancelled);
+    assert!(started.elapsed() < Duration::from_secs(1));
+    cancel.join().unwrap();
+
+    let mut searcher: Searcher<Manager<_>> = Searcher::new(Node::Root);
+    searcher.max_expansions = Some(50);
+    assert_eq!(
+        searcher.try_next(),
+        SearchOutcome::LimitReached(SearchLimit::Expansions)
+    );
+    assert_eq!(searcher.stats.states_expanded, 50);
+    assert_eq!(searcher.stats.states_generated, 10_000 + 49);
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_save() {
+    use crate::*;
+
+    #[derive(Clone, Debug, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+    struct Pos(i32, i32);
+
+    impl Searchable for Pos {
+        fn next_states(&self) -> impl Iterator<Item = Self> {
+            let &Pos(x, y) = self;
+            [Pos(x - 1, y), Pos(x, y - 1), Pos(x + 1, y), Pos(x, y + 1)].into_iter()
+        }
+    }
+
+    impl SolutionIdentifiable for Pos {
+        fn is_solution(&self) -> bool {
+            let &Pos(x, y) = self;
+            x == 5 && y == 5
+        }
+    }
+
+    let uninterrupted = Searcher::<Manager<_>>::new(Pos(0, 0)).next().unwrap();
+
+    let mut searcher: Searcher<Manager<_>> = Searcher::new(Pos(0, 0));
+    searcher.max_expansions = Some(20);
+    assert_eq!(
+        searcher.try_next(),
+        SearchOutcome::LimitReached(SearchLimit::Expansions)
+    );
+
+    let mut checkpoint = Vec::new();
+    searcher.save(&mut checkpoint).unwrap();
+    let mut resumed: Searcher<Manager<Pos>> = Searcher::load(checkpoint.as_slice()).unwrap();
+    assert_eq!(resumed.stats, searcher.stats);
+    resumed.max_expansions = None;
+    assert_eq!(resumed.next(), Some(uninterrupted));
+}
@@ -1,8 +1,16 @@
-use std::collections::VecDeque;
+use std::{collections::VecDeque, sync::Arc};
 
 use crate::{prepare_result_from_state_parent_map, ExplorationManager, Searchable, StateParent};
 
 /// unguided, solution-route yielding, unoptimized search manager.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(
+        serialize = "S: serde::Serialize",
+        deserialize = "S: serde::Deserialize<'de>"
+    ))
+)]
 pub struct Manager<S> {
     fringe: VecDeque<StateParent<S>>,
     parents: Vec<StateParent<S>>,
@@ -22,14 +30,17 @@ where
 
     type NextStatesIterItem = S;
 
-    fn initialize(initial_state: S) -> Self {
-        let initial_pair = StateParent {
-            state: initial_state,
-            parent: None,
-        };
+    fn initialize(initial_states: Vec<S>) -> Self {
+        let fringe = initial_states
+            .into_iter()
+            .map(|state| StateParent {
+                state: Arc::new(state),
+                parent: None,
+            })
+            .collect();
         Self {
-            fringe: VecDeque::from([initial_pair.clone()]),
-            parents: vec![initial_pair],
+            fringe,
+            parents: Vec::new(),
             depth_first: false,
         }
     }
@@ -60,13 +71,23 @@ where
 
     fn prepare_state(&self, context: &Self::CurrentStateContext, state: S) -> Self::FringeItem {
         StateParent {
-            state,
+            state: Arc::new(state),
             parent: Some(*context),
         }
     }
 
-    fn next_states_iter(current_state: &S) -> impl Iterator<Item = Self::NextStatesIterItem> {
-        current_state.next_states()
+    fn next_states_iter(
+        current_state: &Self::FringeItem,
+    ) -> impl Iterator<Item = Self::NextStatesIterItem> {
+        current_state.as_ref().next_states()
+    }
+
+    fn fringe_size(&self) -> usize {
+        self.fringe.len()
+    }
+
+    fn explored_size(&self) -> usize {
+        0
     }
 }
 
@@ -0,0 +1,6 @@
+//! Module containing all uniform-cost (Dijkstra) based search managers.
+//!
+//! These managers explore states in order of the cumulative cost of reaching them, requiring no heuristic.
+
+pub mod no_route;
+pub mod route;
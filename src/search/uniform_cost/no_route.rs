@@ -0,0 +1,4 @@
+//! Module containing all uniform-cost based, solution-only yielding search managers.
+
+pub mod hashable;
+pub mod unhashable;
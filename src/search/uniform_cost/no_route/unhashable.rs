@@ -0,0 +1,131 @@
+use std::ops::Add;
+
+use num::Zero;
+
+use crate::{
+    ExplorationManager, OrderedSearchable, ScoredFringe, StateCumulativeCost, UniformCostSearchable,
+};
+
+/// uniform-cost, solution-only yielding, unoptimized search manager.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(
+        serialize = "S: serde::Serialize, S::Cost: serde::Serialize",
+        deserialize = "S: serde::Deserialize<'de>, S::Cost: serde::Deserialize<'de>"
+    ))
+)]
+pub struct Manager<S>
+where
+    S: UniformCostSearchable,
+{
+    fringe: ScoredFringe<StateCumulativeCost<S, S::Cost>, S::Cost>,
+}
+
+impl<S> ExplorationManager for Manager<S>
+where
+    S: UniformCostSearchable + Clone,
+    S::Cost: Add<S::Cost, Output = S::Cost> + Zero + Clone,
+{
+    type State = S;
+    type YieldResult = S;
+
+    type FringeItem = StateCumulativeCost<S, S::Cost>;
+
+    type CurrentStateContext = S::Cost;
+
+    type NextStatesIterItem = (S, S::Cost);
+
+    fn initialize(initial_states: Vec<S>) -> Self {
+        let fringe = initial_states
+            .into_iter()
+            .map(|state| OrderedSearchable {
+                score: S::Cost::zero(),
+                state: StateCumulativeCost {
+                    state,
+                    cumulative_cost: S::Cost::zero(),
+                },
+            })
+            .collect();
+        Self { fringe }
+    }
+
+    fn pop_state(&mut self) -> Option<Self::FringeItem> {
+        self.fringe.pop().map(|s| s.state)
+    }
+
+    fn prepare_result_from(&self, item: Self::FringeItem) -> Self::YieldResult {
+        item.state
+    }
+
+    fn valid_state(&mut self, _item: &Self::FringeItem) -> bool {
+        true
+    }
+
+    fn place_state(&mut self, item: Self::FringeItem) {
+        let score = item.cumulative_cost.clone();
+        self.fringe.push(OrderedSearchable { state: item, score })
+    }
+
+    fn register_current_state(&mut self, item: &Self::FringeItem) -> Self::CurrentStateContext {
+        item.cumulative_cost.clone()
+    }
+
+    fn prepare_state(
+        &self,
+        cumulative_cost: &Self::CurrentStateContext,
+        (state, traversal_cost): Self::NextStatesIterItem,
+    ) -> Self::FringeItem {
+        StateCumulativeCost {
+            state,
+            cumulative_cost: cumulative_cost.clone() + traversal_cost,
+        }
+    }
+
+    fn next_states_iter(
+        current_state: &Self::FringeItem,
+    ) -> impl Iterator<Item = Self::NextStatesIterItem> {
+        current_state.as_ref().next_states_with_costs()
+    }
+
+    fn fringe_size(&self) -> usize {
+        self.fringe.len()
+    }
+
+    fn explored_size(&self) -> usize {
+        0
+    }
+}
+
+#[test]
+fn test() {
+    use crate::*;
+
+    #[derive(Clone, Debug, PartialEq)]
+    struct Pos(i32, i32);
+
+    impl UniformCostSearchable for Pos {
+        type Cost = i32;
+
+        fn next_states_with_costs(&self) -> impl Iterator<Item = (Self, Self::Cost)> {
+            let &Pos(x, y) = self;
+            [
+                (Pos(x - 1, y), 1),
+                (Pos(x, y - 1), 1),
+                (Pos(x + 1, y), if y == 0 { 1 } else { 3 }),
+                (Pos(x, y + 1), if x == 3 { 1 } else { 2 }),
+            ]
+            .into_iter()
+        }
+    }
+
+    impl SolutionIdentifiable for Pos {
+        fn is_solution(&self) -> bool {
+            let &Pos(x, y) = self;
+            x == 3 && y == 3
+        }
+    }
+
+    let mut searcher: Searcher<Manager<_>> = Searcher::new(Pos(0, 0));
+    assert_eq!(searcher.next(), Some(Pos(3, 3)));
+}
@@ -0,0 +1,4 @@
+//! Module containing all uniform-cost based, solution-route yielding search managers.
+
+pub mod hashable;
+pub mod unhashable;
@@ -0,0 +1,210 @@
+use std::{hash::Hash, ops::Add, sync::Arc};
+
+use num::Zero;
+
+use crate::{
+    prepare_result_from_state_parent_map, ExplorationManager, NodeItem, Nodes, OrderedSearchable,
+    ScoredFringe, StateParentCumulativeCost, UniformCostSearchable,
+};
+
+/// uniform-cost, solution-route yielding, prior state exploration culling search manager.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(
+        serialize = "S: serde::Serialize, S::Cost: serde::Serialize",
+        deserialize = "S: serde::Deserialize<'de> + Eq + Hash, S::Cost: serde::Deserialize<'de>"
+    ))
+)]
+pub struct Manager<S>
+where
+    S: UniformCostSearchable,
+{
+    explored: Nodes<S, StateParentCumulativeCost<S, S::Cost>>,
+    fringe: ScoredFringe<usize, S::Cost>,
+}
+
+impl<S> ExplorationManager for Manager<S>
+where
+    S: UniformCostSearchable + Clone + Eq + Hash,
+    S::Cost: Add<S::Cost, Output = S::Cost> + Zero + Clone,
+{
+    type State = S;
+    type YieldResult = Vec<S>;
+
+    type FringeItem = NodeItem<StateParentCumulativeCost<S, S::Cost>>;
+
+    type CurrentStateContext = (usize, S::Cost);
+
+    type NextStatesIterItem = (S, S::Cost);
+
+    fn initialize(initial_states: Vec<S>) -> Self {
+        let mut explored = Nodes::new();
+        let fringe = initial_states
+            .into_iter()
+            .filter_map(|state| {
+                explored.insert(StateParentCumulativeCost {
+                    state: Arc::new(state),
+                    parent: None,
+                    cumulative_cost: S::Cost::zero(),
+                })
+            })
+            .map(|index| OrderedSearchable {
+                state: index,
+                score: S::Cost::zero(),
+            })
+            .collect();
+        Self { explored, fringe }
+    }
+
+    fn pop_state(&mut self) -> Option<Self::FringeItem> {
+        loop {
+            let index = self.fringe.pop()?.state;
+            let item = &self.explored[index];
+            // skip stale entries superseded by a cheaper path to the same state
+            if self.explored.index_of(&item.state) != Some(index) {
+                continue;
+            }
+            return Some(NodeItem {
+                item: item.clone(),
+                node: Some(index),
+            });
+        }
+    }
+
+    fn prepare_result_from(&self, NodeItem { item, .. }: Self::FringeItem) -> Self::YieldResult {
+        prepare_result_from_state_parent_map(&self.explored, item.into())
+    }
+
+    fn valid_state(&mut self, NodeItem { item, .. }: &Self::FringeItem) -> bool {
+        self.explored
+            .index_of(&item.state)
+            .is_none_or(|best| item.cumulative_cost < self.explored[best].cumulative_cost)
+    }
+
+    fn place_state(&mut self, NodeItem { item, .. }: Self::FringeItem) {
+        let score = item.cumulative_cost.clone();
+        let index = self.explored.push(item);
+        self.fringe.push(OrderedSearchable {
+            state: index,
+            score,
+        })
+    }
+
+    fn register_current_state(
+        &mut self,
+        NodeItem { item, node }: &Self::FringeItem,
+    ) -> Self::CurrentStateContext {
+        let index = node.expect("Popped items always carry their node");
+        (index, item.cumulative_cost.clone())
+    }
+
+    fn prepare_state(
+        &self,
+        (parent, cumulative_cost): &Self::CurrentStateContext,
+        (state, traversal_cost): Self::NextStatesIterItem,
+    ) -> Self::FringeItem {
+        NodeItem {
+            item: StateParentCumulativeCost {
+                state: Arc::new(state),
+                parent: Some(*parent),
+                cumulative_cost: cumulative_cost.clone() + traversal_cost,
+            },
+            node: None,
+        }
+    }
+
+    fn next_states_iter(
+        current_state: &Self::FringeItem,
+    ) -> impl Iterator<Item = Self::NextStatesIterItem> {
+        current_state.as_ref().next_states_with_costs()
+    }
+
+    fn fringe_size(&self) -> usize {
+        self.fringe.len()
+    }
+
+    fn explored_size(&self) -> usize {
+        self.explored.states_len()
+    }
+}
+
+#[test]
+fn test() {
+    use crate::*;
+
+    #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+    struct Pos(i32, i32);
+
+    impl UniformCostSearchable for Pos {
+        type Cost = i32;
+
+        fn next_states_with_costs(&self) -> impl Iterator<Item = (Self, Self::Cost)> {
+            let &Pos(x, y) = self;
+            [
+                (Pos(x - 1, y), 1),
+                (Pos(x, y - 1), 1),
+                (Pos(x + 1, y), if y == 0 { 1 } else { 3 }),
+                (Pos(x, y + 1), if x == 3 { 1 } else { 2 }),
+            ]
+            .into_iter()
+        }
+    }
+
+    impl SolutionIdentifiable for Pos {
+        fn is_solution(&self) -> bool {
+            let &Pos(x, y) = self;
+            x == 3 && y == 3
+        }
+    }
+
+    let mut searcher: Searcher<Manager<_>> = Searcher::new(Pos(0, 0));
+    assert_eq!(
+        searcher.next(),
+        Some(vec![
+            Pos(0, 0),
+            Pos(1, 0),
+            Pos(2, 0),
+            Pos(3, 0),
+            Pos(3, 1),
+            Pos(3, 2),
+            Pos(3, 3)
+        ])
+    );
+}
+
+#[test]
+fn test_multi() {
+    use crate::*;
+
+    #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+    struct Pos(i32, i32);
+
+    impl UniformCostSearchable for Pos {
+        type Cost = i32;
+
+        fn next_states_with_costs(&self) -> impl Iterator<Item = (Self, Self::Cost)> {
+            let &Pos(x, y) = self;
+            [
+                (Pos(x - 1, y), 1),
+                (Pos(x, y - 1), 1),
+                (Pos(x + 1, y), if y == 0 { 1 } else { 3 }),
+                (Pos(x, y + 1), if x == 3 { 1 } else { 2 }),
+            ]
+            .into_iter()
+        }
+    }
+
+    impl SolutionIdentifiable for Pos {
+        fn is_solution(&self) -> bool {
+            let &Pos(x, y) = self;
+            x == 3 && y == 3
+        }
+    }
+
+    let mut searcher: Searcher<Manager<_>> = Searcher::new_multi([Pos(0, 0), Pos(1, 3), Pos(6, 3)]);
+    assert_eq!(
+        searcher.next(),
+        Some(vec![Pos(6, 3), Pos(5, 3), Pos(4, 3), Pos(3, 3)])
+    );
+}